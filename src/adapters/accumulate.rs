@@ -0,0 +1,156 @@
+use core::{fmt, marker::Destruct};
+
+use array_trait::length::LengthValue;
+
+use crate::{Bulk, Map, SplitBulk, util::Accumulator};
+
+/// A bulk that yields the running results of folding `bulk` with `f`.
+///
+/// This `struct` is created by the [`accumulate`](Bulk::accumulate) method on [`Bulk`]. See its
+/// documentation for more.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Accumulate<I, T, F>
+where
+    I: Bulk
+{
+    bulk: I,
+    init: T,
+    f: F
+}
+
+impl<I, T, F> Accumulate<I, T, F>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, init: T, f: F) -> Self
+    {
+        Self {
+            bulk,
+            init,
+            f
+        }
+    }
+}
+
+impl<I, T, F> fmt::Debug for Accumulate<I, T, F>
+where
+    I: Bulk + fmt::Debug,
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, init, f: _ } = self;
+        f.debug_struct("Accumulate").field("bulk", bulk).field("init", init).finish()
+    }
+}
+
+const impl<I, T, F> IntoIterator for Accumulate<I, T, F>
+where
+    I: Bulk + ~const IntoIterator<IntoIter: ~const Iterator>,
+    T: Clone,
+    F: FnMut(T, I::Item) -> T
+{
+    type Item = T;
+    type IntoIter = core::iter::Map<I::IntoIter, Accumulator<T, F>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, init, f } = self;
+        bulk.into_iter().map(Accumulator { acc: Some(init), f })
+    }
+}
+const impl<I, T, F> Bulk for Accumulate<I, T, F>
+where
+    I: ~const Bulk<Item: ~const Destruct>,
+    T: ~const Clone + ~const Destruct,
+    F: ~const FnMut(T, I::Item) -> T + ~const Destruct
+{
+    type MinLength = I::MinLength;
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, init: _, f: _ } = self;
+        bulk.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { bulk, init: _, f: _ } = self;
+        bulk.is_empty()
+    }
+
+    fn for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, init, f: accumulate } = self;
+        Map::new(bulk, Accumulator { acc: Some(init), f: accumulate }).for_each(f)
+    }
+    fn try_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const core::ops::Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, init, f: accumulate } = self;
+        Map::new(bulk, Accumulator { acc: Some(init), f: accumulate }).try_for_each(f)
+    }
+}
+const impl<I, T, F, L> SplitBulk<L> for Accumulate<I, T, F>
+where
+    I: ~const SplitBulk<L, Item: ~const Destruct, Left: ~const Bulk + ~const Clone, Right: ~const Bulk>,
+    T: ~const Clone + ~const Destruct,
+    F: ~const FnMut(T, I::Item) -> T + ~const Clone + ~const Destruct,
+    L: LengthValue
+{
+    type Left = Accumulate<I::Left, T, F>;
+    type Right = Accumulate<I::Right, T, F>;
+
+    fn split_at(Self { bulk, init, mut f }: Self, n: L) -> (Self::Left, Self::Right)
+    where
+        Self: Sized
+    {
+        let (left, right) = bulk.split_at(n);
+        let right_init = left.clone().fold(init.clone(), &mut f);
+        (
+            Accumulate::new(left, init, f.clone()),
+            Accumulate::new(right, right_init, f)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5];
+
+        let b: [_; _] = a.into_bulk()
+            .accumulate(0, |acc, x| acc + x)
+            .collect();
+
+        assert_eq!(b, [1, 3, 6, 10, 15]);
+    }
+
+    #[test]
+    fn split()
+    {
+        let a = ["a", "b", "c", "d"];
+
+        let (left, right) = a.into_bulk()
+            .accumulate(String::new(), |acc, x| acc + x)
+            .split_at([(); 1]);
+
+        let left: [_; _] = left.collect();
+        let right: [_; _] = right.collect();
+
+        assert_eq!(left, ["a"]);
+        assert_eq!(right, ["ab", "abc", "abcd"]);
+    }
+}
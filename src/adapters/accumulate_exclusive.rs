@@ -0,0 +1,136 @@
+use core::{fmt, marker::Destruct, ops::Try};
+
+use array_trait::length;
+
+use crate::{Bulk, IntoContained, Map, util::Accumulator};
+
+/// A bulk that yields the initial value, followed by the running results of folding `bulk` with `f`.
+///
+/// This `struct` is created by the [`accumulate_exclusive`](Bulk::accumulate_exclusive) method on [`Bulk`]. See its
+/// documentation for more.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct AccumulateExclusive<I, T, F>
+where
+    I: Bulk
+{
+    bulk: I,
+    init: T,
+    f: F
+}
+
+impl<I, T, F> AccumulateExclusive<I, T, F>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, init: T, f: F) -> Self
+    {
+        Self {
+            bulk,
+            init,
+            f
+        }
+    }
+}
+
+impl<I, T, F> fmt::Debug for AccumulateExclusive<I, T, F>
+where
+    I: Bulk + fmt::Debug,
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, init, f: _ } = self;
+        f.debug_struct("AccumulateExclusive").field("bulk", bulk).field("init", init).finish()
+    }
+}
+
+/*const*/ impl<I, T, F> IntoIterator for AccumulateExclusive<I, T, F>
+where
+    I: Bulk,
+    T: Clone,
+    F: FnMut(T, I::Item) -> T
+{
+    type Item = T;
+    type IntoIter = <<core::iter::Chain<core::iter::Once<T>, core::iter::Map<I::IntoIter, Accumulator<T, F>>> as IntoContained>::IntoContained as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, init, f } = self;
+        unsafe {
+            core::iter::once(init.clone())
+                .chain(bulk.into_iter().map(Accumulator { acc: Some(init), f }))
+                .into_contained()
+                .into_iter()
+        }
+    }
+}
+const impl<I, T, F> Bulk for AccumulateExclusive<I, T, F>
+where
+    I: ~const Bulk<Item: ~const Destruct> + ~const Destruct,
+    T: ~const Clone + ~const Destruct,
+    F: ~const FnMut(T, I::Item) -> T + ~const Destruct
+{
+    type MinLength = length::Add<I::MinLength, [(); 1]>;
+    type MaxLength = length::Add<I::MaxLength, [(); 1]>;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, init: _, f: _ } = self;
+        bulk.len() + 1
+    }
+    fn is_empty(&self) -> bool
+    {
+        false
+    }
+
+    fn for_each<FF>(self, mut f: FF)
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, init, f: accumulate } = self;
+        f(init.clone());
+        Map::new(bulk, Accumulator { acc: Some(init), f: accumulate }).for_each(f)
+    }
+    fn try_for_each<FF, R>(self, mut f: FF) -> R
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, init, f: accumulate } = self;
+        f(init.clone())?;
+        Map::new(bulk, Accumulator { acc: Some(init), f: accumulate }).try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [3u32, 1, 4, 1, 5, 9, 2, 6];
+
+        let offsets: [u32; 9] = a.into_bulk()
+            .accumulate_exclusive(0, |acc, x| acc + x)
+            .collect();
+
+        assert_eq!(offsets, [0, 3, 4, 8, 9, 14, 23, 25, 31]);
+    }
+
+    #[test]
+    fn empty()
+    {
+        let a: [u32; 0] = [];
+
+        let offsets: [u32; 1] = a.into_bulk()
+            .accumulate_exclusive(7, |acc, x| acc + x)
+            .collect();
+
+        assert_eq!(offsets, [7]);
+    }
+}
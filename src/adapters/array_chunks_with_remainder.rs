@@ -8,7 +8,8 @@ where
     I: Bulk
 {
     bulk: ArrayChunks<I, N>,
-    remainder: &'a mut ArrayBuffer<I::Item, N, REV>
+    remainder: &'a mut ArrayBuffer<I::Item, N, REV>,
+    skip: Option<usize>
 }
 
 impl<'a, I, const N: usize, const REV: bool> ArrayChunksWithRemainder<'a, I, N, REV>
@@ -20,7 +21,20 @@ where
     {
         Self {
             bulk: ArrayChunks::new(bulk),
-            remainder
+            remainder,
+            skip: None
+        }
+    }
+
+    /// Like [`new`](Self::new), but skips exactly `skip` elements before the first chunk,
+    /// instead of deciding from `REV`.
+    #[track_caller]
+    pub(crate) const fn new_with_skip(bulk: I, remainder: &'a mut ArrayBuffer<I::Item, N, REV>, skip: usize) -> Self
+    {
+        Self {
+            bulk: ArrayChunks::new(bulk),
+            remainder,
+            skip: Some(skip)
         }
     }
 
@@ -28,8 +42,12 @@ where
     where
         I: ~const Bulk
     {
-        let Self { bulk, remainder: _ } = self;
-        bulk.skip_len::<REV>()
+        let Self { bulk, remainder: _, skip } = self;
+        match skip
+        {
+            Some(skip) => *skip,
+            None => bulk.skip_len::<REV>()
+        }
     }
 
     const fn for_each_closure<F>(self, f: F) -> (I, impl ~const FnMut(I::Item) + ~const Destruct + 'a)
@@ -78,7 +96,7 @@ where
         }
 
         let skip = self.skip_len();
-        let Self { bulk, remainder } = self;
+        let Self { bulk, remainder, skip: _ } = self;
         (
             bulk.into_inner(),
             Closure {
@@ -141,7 +159,7 @@ where
         }
 
         let skip = self.skip_len();
-        let Self { bulk, remainder } = self;
+        let Self { bulk, remainder, skip: _ } = self;
         (
             bulk.into_inner(),
             Closure {
@@ -162,7 +180,7 @@ where
 
     fn into_iter(self) -> Self::IntoIter
     {
-        let Self { bulk, remainder } = self;
+        let Self { bulk, remainder, skip: _ } = self;
         iter::ArrayChunksWithRemainder::new(bulk.into_iter(), remainder)
     }
 }
@@ -176,7 +194,7 @@ where
     #[inline]
     fn len(&self) -> usize
     {
-        let Self { bulk, remainder: _ } = self;
+        let Self { bulk, remainder: _, skip: _ } = self;
         bulk.len()
     }
     
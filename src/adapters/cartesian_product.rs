@@ -0,0 +1,280 @@
+use core::{fmt, ops::Try};
+
+use array_trait::length;
+
+use crate::{Bulk, DoubleEndedBulk, IntoBulk};
+
+/// Converts the arguments to bulks and pairs up every element of the first with every element of the second.
+///
+/// See the documentation of [`Bulk::cartesian_product`](crate::Bulk::cartesian_product) for more.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let bulk = bulks::cartesian_product([1, 2], ['a', 'b']);
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b')]);
+/// ```
+pub const fn cartesian_product<A, B>(a: A, b: B) -> CartesianProduct<A::IntoBulk, B::IntoBulk>
+where
+    A: ~const IntoBulk<Item: Clone>,
+    B: ~const IntoBulk<IntoBulk: Clone>
+{
+    CartesianProduct::new(a.into_bulk(), b.into_bulk())
+}
+
+/// A bulk that pairs up every element of one bulk with every element of another.
+///
+/// This `struct` is created by [`cartesian_product`] or [`Bulk::cartesian_product`].
+/// See their documentation for more.
+#[derive(Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct CartesianProduct<A, B>
+where
+    A: Bulk<Item: Clone>,
+    B: Bulk + Clone
+{
+    a: A,
+    b: B
+}
+
+impl<A, B> CartesianProduct<A, B>
+where
+    A: Bulk<Item: Clone>,
+    B: Bulk + Clone
+{
+    pub(crate) const fn new(a: A, b: B) -> Self
+    {
+        Self { a, b }
+    }
+}
+
+impl<A, B> fmt::Debug for CartesianProduct<A, B>
+where
+    A: Bulk<Item: Clone> + fmt::Debug,
+    B: Bulk + Clone + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("CartesianProduct").field("a", &self.a).field("b", &self.b).finish()
+    }
+}
+
+mod private
+{
+    pub struct IntoIter<A, B>
+    where
+        A: Iterator<Item: Clone>,
+        B: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        pub a: A,
+        pub b: B,
+        pub front: Option<(A::Item, B::IntoIter)>,
+        pub back: Option<(A::Item, B::IntoIter)>
+    }
+
+    impl<A, B> Iterator for IntoIter<A, B>
+    where
+        A: Iterator<Item: Clone>,
+        B: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        type Item = (A::Item, B::Item);
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let Self { a, b, front, back } = self;
+            loop
+            {
+                if let Some((x, ys)) = front
+                    && let Some(y) = ys.next()
+                {
+                    return Some((x.clone(), y))
+                }
+                match a.next()
+                {
+                    Some(x) => *front = Some((x, b.clone().into_iter())),
+                    None => {
+                        let (x, ys) = back.as_mut()?;
+                        return Some((x.clone(), ys.next()?))
+                    }
+                }
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let Self { a, b, front, back } = self;
+            let n = b.clone().into_iter().len();
+            let (min, max) = a.size_hint();
+            let extra = front.as_ref().map_or(0, |(_, ys)| ys.len()) + back.as_ref().map_or(0, |(_, ys)| ys.len());
+            (
+                min.saturating_mul(n).saturating_add(extra),
+                max.and_then(|max| max.checked_mul(n)?.checked_add(extra))
+            )
+        }
+    }
+    impl<A, B> ExactSizeIterator for IntoIter<A, B>
+    where
+        A: ExactSizeIterator<Item: Clone>,
+        B: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        fn len(&self) -> usize
+        {
+            let Self { a, b, front, back } = self;
+            front.as_ref().map_or(0, |(_, ys)| ys.len())
+                + back.as_ref().map_or(0, |(_, ys)| ys.len())
+                + a.len()*b.clone().into_iter().len()
+        }
+    }
+    impl<A, B> DoubleEndedIterator for IntoIter<A, B>
+    where
+        A: DoubleEndedIterator<Item: Clone>,
+        B: IntoIterator<IntoIter: ExactSizeIterator + DoubleEndedIterator> + Clone
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            let Self { a, b, front, back } = self;
+            loop
+            {
+                if let Some((x, ys)) = back
+                    && let Some(y) = ys.next_back()
+                {
+                    return Some((x.clone(), y))
+                }
+                match a.next_back()
+                {
+                    Some(x) => *back = Some((x, b.clone().into_iter())),
+                    None => {
+                        let (x, ys) = front.as_mut()?;
+                        return Some((x.clone(), ys.next_back()?))
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<A, B> IntoIterator for CartesianProduct<A, B>
+where
+    A: Bulk<Item: Clone>,
+    B: Bulk + Clone
+{
+    type Item = (A::Item, B::Item);
+    type IntoIter = private::IntoIter<A::IntoIter, B>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { a, b } = self;
+        private::IntoIter {
+            a: a.into_iter(),
+            b,
+            front: None,
+            back: None
+        }
+    }
+}
+impl<A, B> Bulk for CartesianProduct<A, B>
+where
+    A: Bulk<Item: Clone>,
+    B: Bulk + Clone
+{
+    type MinLength = length::Mul<A::MinLength, B::MinLength>;
+    type MaxLength = length::Mul<A::MaxLength, B::MaxLength>;
+
+    fn len(&self) -> usize
+    {
+        let Self { a, b } = self;
+        a.len()*b.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { a, b } = self;
+        a.is_empty() || b.is_empty()
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { a, b } = self;
+        a.for_each(|x| b.clone().for_each(|y| f((x.clone(), y))))
+    }
+    fn try_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        let Self { a, b } = self;
+        a.try_for_each(|x| b.clone().try_for_each(|y| f((x.clone(), y))))
+    }
+}
+impl<A, B> DoubleEndedBulk for CartesianProduct<A, B>
+where
+    A: DoubleEndedBulk<Item: Clone>,
+    B: DoubleEndedBulk + Clone
+{
+    fn rev_for_each<F>(self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { a, b } = self;
+        a.rev_for_each(|x| b.clone().rev_for_each(|y| f((x.clone(), y))))
+    }
+    fn try_rev_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        let Self { a, b } = self;
+        a.try_rev_for_each(|x| b.clone().try_rev_for_each(|y| f((x.clone(), y))))
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3];
+        let b = ['a', 'b', 'c', 'd'];
+
+        let grid: [_; 12] = a.into_bulk()
+            .cartesian_product(b)
+            .collect();
+        assert_eq!(grid[0], (1, 'a'));
+        assert_eq!(grid[5], (2, 'b'));
+        assert_eq!(grid[11], (3, 'd'));
+
+        let rev: Vec<_> = [1, 2].into_bulk()
+            .cartesian_product(['a', 'b'])
+            .rev()
+            .collect();
+        assert_eq!(rev, [(2, 'b'), (2, 'a'), (1, 'b'), (1, 'a')]);
+    }
+
+    #[test]
+    fn iter()
+    {
+        let mut iter = [1, 2].into_bulk()
+            .cartesian_product([3, 4])
+            .into_iter();
+
+        assert_eq!(iter.len(), 4);
+        assert_eq!(iter.next(), Some((1, 3)));
+        assert_eq!(iter.next_back(), Some((2, 4)));
+        assert_eq!(iter.len(), 2);
+
+        let rest: Vec<_> = iter.collect();
+        assert_eq!(rest, [(1, 4), (2, 3)]);
+    }
+}
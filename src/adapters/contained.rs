@@ -25,6 +25,11 @@ where
     {
         self.iter.next()
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        self.iter.size_hint()
+    }
 }
 impl<I> ExactSizeIterator for Contained<I>
 where
@@ -0,0 +1,344 @@
+use core::{fmt, ops::Try, ptr::Pointee};
+
+use array_trait::length::{self, Length, LengthValue};
+
+use crate::{Bulk, DoubleEndedBulk, IntoBulk};
+
+/// Creates a bulk that repeats the whole of `iterable` `n` times.
+///
+/// See the documentation of [`Bulk::cycle_n`](crate::Bulk::cycle_n) for more.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let bulk = bulks::cycle_n([1, 2], [(); 3]);
+///
+/// let a: [_; _] = bulk.collect();
+/// assert_eq!(a, [1, 2, 1, 2, 1, 2]);
+/// ```
+pub const fn cycle_n<I, L>(iterable: I, n: L) -> CycleN<I::IntoBulk, L::Length<()>>
+where
+    I: ~const IntoBulk<IntoBulk: Clone>,
+    L: LengthValue
+{
+    CycleN::new(iterable.into_bulk(), n)
+}
+
+/// A bulk that repeats the whole of `bulk` an exact number of times.
+///
+/// This `struct` is created by the [`cycle_n`](Bulk::cycle_n) method on [`Bulk`]. See its
+/// documentation for more.
+#[derive(Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct CycleN<I, N = [()]>
+where
+    I: Bulk + Clone,
+    N: Length<Elem = ()> + ?Sized
+{
+    bulk: I,
+    n: <N as Pointee>::Metadata
+}
+
+impl<I, N> CycleN<I, N>
+where
+    I: Bulk + Clone,
+    N: Length<Elem = ()> + ?Sized
+{
+    pub(crate) const fn new(bulk: I, n: N::Value) -> Self
+    {
+        Self { bulk, n: length::value::into_metadata(n) }
+    }
+}
+
+impl<I, N> fmt::Debug for CycleN<I, N>
+where
+    I: Bulk + Clone + fmt::Debug,
+    N: Length<Elem = ()> + ?Sized
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, n } = self;
+        f.debug_struct("CycleN").field("bulk", bulk).field("n", &length::len_metadata::<N>(*n)).finish()
+    }
+}
+
+mod private
+{
+    pub struct IntoIter<I>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        pub source: Option<I>,
+        pub source_len: usize,
+        pub front: Option<I::IntoIter>,
+        pub back: Option<I::IntoIter>,
+        pub remaining: usize
+    }
+
+    impl<I> IntoIter<I>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        /// Starts the next repetition, moving the source into the last one instead of cloning it.
+        fn repeat(source: &mut Option<I>, remaining: &mut usize) -> Option<I::IntoIter>
+        {
+            *remaining -= 1;
+            if *remaining == 0
+            {
+                source.take()
+            }
+            else
+            {
+                source.clone()
+            }.map(IntoIterator::into_iter)
+        }
+    }
+
+    impl<I> Iterator for IntoIter<I>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        type Item = I::Item;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let Self { source, source_len: _, front, back, remaining } = self;
+            loop
+            {
+                if let Some(x) = front.as_mut().and_then(Iterator::next)
+                {
+                    return Some(x)
+                }
+                if *remaining == 0
+                {
+                    return back.as_mut()?.next()
+                }
+                *front = Self::repeat(source, remaining)
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let len = self.len();
+            (len, Some(len))
+        }
+    }
+    impl<I> ExactSizeIterator for IntoIter<I>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator> + Clone
+    {
+        fn len(&self) -> usize
+        {
+            let Self { source: _, source_len, front, back, remaining } = self;
+            front.as_ref().map_or(0, ExactSizeIterator::len)
+                + back.as_ref().map_or(0, ExactSizeIterator::len)
+                + *remaining*source_len
+        }
+    }
+    impl<I> DoubleEndedIterator for IntoIter<I>
+    where
+        I: IntoIterator<IntoIter: ExactSizeIterator + DoubleEndedIterator> + Clone
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            let Self { source, source_len: _, front, back, remaining } = self;
+            loop
+            {
+                if let Some(x) = back.as_mut().and_then(DoubleEndedIterator::next_back)
+                {
+                    return Some(x)
+                }
+                if *remaining == 0
+                {
+                    return front.as_mut()?.next_back()
+                }
+                *back = Self::repeat(source, remaining)
+            }
+        }
+    }
+}
+
+impl<I, N> IntoIterator for CycleN<I, N>
+where
+    I: Bulk + Clone,
+    N: Length<Elem = ()> + ?Sized
+{
+    type Item = I::Item;
+    type IntoIter = private::IntoIter<I>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, n } = self;
+        private::IntoIter {
+            source_len: bulk.len(),
+            source: Some(bulk),
+            front: None,
+            back: None,
+            remaining: length::len_metadata::<N>(n)
+        }
+    }
+}
+impl<I, N> Bulk for CycleN<I, N>
+where
+    I: Bulk + Clone,
+    N: Length<Elem = ()> + ?Sized
+{
+    type MinLength = length::Mul<I::MinLength, N>;
+    type MaxLength = length::Mul<I::MaxLength, N>;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, n } = self;
+        bulk.len()*length::len_metadata::<N>(*n)
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { bulk, n } = self;
+        bulk.is_empty() || length::len_metadata::<N>(*n) == 0
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { bulk, n } = self;
+        let n = length::len_metadata::<N>(n);
+        if n == 0
+        {
+            return
+        }
+        for _ in 1..n
+        {
+            bulk.clone().for_each(&mut f)
+        }
+        bulk.for_each(f)
+    }
+    fn try_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        let Self { bulk, n } = self;
+        let n = length::len_metadata::<N>(n);
+        if n == 0
+        {
+            return R::from_output(())
+        }
+        for _ in 1..n
+        {
+            bulk.clone().try_for_each(&mut f)?
+        }
+        bulk.try_for_each(f)
+    }
+}
+impl<I, N> DoubleEndedBulk for CycleN<I, N>
+where
+    I: DoubleEndedBulk + Clone,
+    N: Length<Elem = ()> + ?Sized
+{
+    fn rev_for_each<F>(self, mut f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { bulk, n } = self;
+        let n = length::len_metadata::<N>(n);
+        if n == 0
+        {
+            return
+        }
+        for _ in 1..n
+        {
+            bulk.clone().rev_for_each(&mut f)
+        }
+        bulk.rev_for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        let Self { bulk, n } = self;
+        let n = length::len_metadata::<N>(n);
+        if n == 0
+        {
+            return R::from_output(())
+        }
+        for _ in 1..n
+        {
+            bulk.clone().try_rev_for_each(&mut f)?
+        }
+        bulk.try_rev_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3];
+
+        let b: [_; _] = a.into_bulk()
+            .cycle_n([(); 2])
+            .collect();
+        assert_eq!(b, [1, 2, 3, 1, 2, 3]);
+
+        let c: Vec<_> = a.into_bulk()
+            .cycle_n(2)
+            .rev()
+            .collect();
+        assert_eq!(c, [3, 2, 1, 3, 2, 1]);
+    }
+
+    #[test]
+    fn iter()
+    {
+        let mut iter = [1, 2].into_bulk()
+            .cycle_n(3)
+            .into_iter();
+
+        assert_eq!(iter.len(), 6);
+        assert_eq!(iter.next(), Some(1));
+        assert_eq!(iter.next_back(), Some(2));
+        assert_eq!(iter.len(), 4);
+
+        let rest: Vec<_> = iter.collect();
+        assert_eq!(rest, [2, 1, 2, 1]);
+    }
+
+    #[test]
+    fn clones()
+    {
+        use core::cell::Cell;
+
+        struct Tracked<'a>(&'a Cell<usize>);
+
+        impl Clone for Tracked<'_>
+        {
+            fn clone(&self) -> Self
+            {
+                self.0.set(self.0.get() + 1);
+                Self(self.0)
+            }
+        }
+
+        let clones = Cell::new(0);
+        let iter = [Tracked(&clones)].into_bulk()
+            .cycle_n(3)
+            .into_iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(clones.get(), 0);
+        assert_eq!(iter.count(), 3);
+        assert_eq!(clones.get(), 2);
+    }
+}
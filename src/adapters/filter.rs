@@ -0,0 +1,183 @@
+use core::fmt;
+
+use crate::{Bulk, DoubleEndedBulk, util::Counted};
+
+/// A bulk that filters the elements of `bulk` with `predicate`.
+///
+/// This `struct` is created by the [`filter`](Bulk::filter) method on [`Bulk`]. See its
+/// documentation for more.
+///
+/// # Notes about length
+///
+/// The length of a [`Filter`] can't be known without evaluating the predicate, so iterating it only gives an upper bound
+/// as its size hint. [`len`](Bulk::len) is found by running `predicate` on a clone of the bulk, so any side effects of `predicate`
+/// will happen again every time the length is needed.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Filter<I, P>
+where
+    I: Bulk
+{
+    bulk: I,
+    predicate: P
+}
+
+impl<I, P> Filter<I, P>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, predicate: P) -> Self
+    {
+        Self {
+            bulk,
+            predicate
+        }
+    }
+}
+
+impl<I, P> fmt::Debug for Filter<I, P>
+where
+    I: Bulk + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, predicate: _ } = self;
+        f.debug_struct("Filter").field("bulk", bulk).finish()
+    }
+}
+
+impl<I, P> IntoIterator for Filter<I, P>
+where
+    I: Bulk,
+    P: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+    type IntoIter = Counted<core::iter::Filter<I::IntoIter, P>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, predicate } = self;
+        Counted::new(bulk.into_iter().filter(predicate))
+    }
+}
+impl<I, P> Bulk for Filter<I, P>
+where
+    I: Bulk<IntoIter: Clone> + Clone,
+    P: FnMut(&I::Item) -> bool + Clone
+{
+    type MinLength = [(); 0];
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        self.clone()
+            .into_iter()
+            .count()
+    }
+
+    fn first(self) -> Option<Self::Item>
+    where
+        Self: Sized
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().find(predicate)
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().filter(predicate).for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().filter(predicate).try_for_each(f)
+    }
+}
+impl<I, P> DoubleEndedBulk for Filter<I, P>
+where
+    I: DoubleEndedBulk<IntoIter: Clone> + Clone,
+    P: FnMut(&I::Item) -> bool + Clone
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().filter(predicate).rev().for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().filter(predicate).rev().try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5, 6];
+
+        let bulk = a.bulk()
+            .filter(|&&x| x % 2 == 0);
+        assert_eq!(bulk.len(), 3);
+
+        let b: Vec<_> = bulk.copied().collect();
+        assert_eq!(b, [2, 4, 6]);
+    }
+
+    #[test]
+    fn rev()
+    {
+        let a = [1, 2, 3, 4, 5, 6];
+
+        let b: Vec<_> = a.into_bulk()
+            .filter(|x| x % 3 != 0)
+            .rev()
+            .collect();
+        assert_eq!(b, [5, 4, 2, 1]);
+
+        let c: Vec<_> = a.bulk()
+            .filter(|&&x| x % 2 == 0)
+            .enumerate()
+            .rev()
+            .collect();
+        assert_eq!(c, [(2, &6), (1, &4), (0, &2)]);
+    }
+
+    #[test]
+    fn bulk_mut()
+    {
+        let mut a = [1, 2, 3, 4, 5, 6];
+
+        let calls = core::cell::Cell::new(0);
+        let iter = a.bulk_mut()
+            .filter(|x| {
+                calls.set(calls.get() + 1);
+                **x % 2 == 0
+            })
+            .into_iter();
+        assert_eq!(iter.size_hint(), (0, Some(6)));
+
+        Iterator::for_each(iter, |x| *x = 0);
+        assert_eq!(calls.get(), 6);
+        assert_eq!(a, [1, 0, 3, 0, 5, 0]);
+    }
+}
@@ -0,0 +1,145 @@
+use core::fmt;
+
+use crate::{Bulk, DoubleEndedBulk, util::Counted};
+
+/// A bulk that uses `f` to both filter and map elements from `bulk`.
+///
+/// This `struct` is created by the [`filter_map`](Bulk::filter_map) method on [`Bulk`]. See its
+/// documentation for more.
+///
+/// # Notes about length
+///
+/// The length of a [`FilterMap`] can't be known without evaluating `f`, so iterating it only gives an upper bound
+/// as its size hint. [`len`](Bulk::len) is found by running `f` on a clone of the bulk, so any side effects of `f`
+/// will happen again every time the length is needed.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct FilterMap<I, F>
+where
+    I: Bulk
+{
+    bulk: I,
+    f: F
+}
+
+impl<I, F> FilterMap<I, F>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, f: F) -> Self
+    {
+        Self {
+            bulk,
+            f
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for FilterMap<I, F>
+where
+    I: Bulk + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, f: _ } = self;
+        f.debug_struct("FilterMap").field("bulk", bulk).finish()
+    }
+}
+
+impl<I, F, B> IntoIterator for FilterMap<I, F>
+where
+    I: Bulk,
+    F: FnMut(I::Item) -> Option<B>
+{
+    type Item = B;
+    type IntoIter = Counted<core::iter::FilterMap<I::IntoIter, F>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, f } = self;
+        Counted::new(bulk.into_iter().filter_map(f))
+    }
+}
+impl<I, F, B> Bulk for FilterMap<I, F>
+where
+    I: Bulk<IntoIter: Clone> + Clone,
+    F: FnMut(I::Item) -> Option<B> + Clone
+{
+    type MinLength = [(); 0];
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        self.clone()
+            .into_iter()
+            .count()
+    }
+
+    fn first(self) -> Option<Self::Item>
+    where
+        Self: Sized
+    {
+        let Self { bulk, f } = self;
+        bulk.into_iter().find_map(f)
+    }
+
+    fn for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: FnMut(Self::Item)
+    {
+        let Self { bulk, f: filter_map } = self;
+        bulk.into_iter().filter_map(filter_map).for_each(f)
+    }
+    fn try_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        FF: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        let Self { bulk, f: filter_map } = self;
+        bulk.into_iter().filter_map(filter_map).try_for_each(f)
+    }
+}
+impl<I, F, B> DoubleEndedBulk for FilterMap<I, F>
+where
+    I: DoubleEndedBulk<IntoIter: Clone> + Clone,
+    F: FnMut(I::Item) -> Option<B> + Clone
+{
+    fn rev_for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: FnMut(Self::Item)
+    {
+        let Self { bulk, f: filter_map } = self;
+        bulk.into_iter().filter_map(filter_map).rev().for_each(f)
+    }
+    fn try_rev_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        FF: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        let Self { bulk, f: filter_map } = self;
+        bulk.into_iter().filter_map(filter_map).rev().try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = ["1", "two", "NaN", "four", "5"];
+
+        let bulk = a.into_bulk()
+            .filter_map(|s| s.parse::<i32>().ok());
+        assert_eq!(bulk.len(), 2);
+
+        let b: Vec<_> = bulk.collect();
+        assert_eq!(b, [1, 5]);
+    }
+}
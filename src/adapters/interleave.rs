@@ -0,0 +1,462 @@
+use core::{fmt, marker::Destruct, mem::MaybeUninit};
+
+use array_trait::length;
+
+use crate::{Bulk, BulkLength, DoubleEndedBulk, IntoBulk, LengthMismatch, SameLength, UnzipBuffer};
+
+use self::private::MultipleOf;
+
+/// Converts the arguments to bulks and interleaves their elements, alternating between the two.
+///
+/// See the documentation of [`Bulk::interleave`](crate::Bulk::interleave) for more.
+///
+/// # Panics
+///
+/// Panics if the lengths of the bulks differ.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let left = [1, 3, 5];
+/// let right = [2, 4, 6];
+///
+/// let bulk = bulks::interleave(left, right);
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [1, 2, 3, 4, 5, 6]);
+/// ```
+#[track_caller]
+pub const fn interleave<A, B>(a: A, b: B) -> Interleave<A::IntoBulk, B::IntoBulk>
+where
+    A: ~const IntoBulk<IntoBulk: ~const Destruct>,
+    B: ~const IntoBulk<Item = A::Item, IntoBulk: ~const Destruct>,
+    A::IntoBulk: SameLength<B::IntoBulk>
+{
+    let a = a.into_bulk();
+    let b = b.into_bulk();
+    let lhs = a.len();
+    let rhs = b.len();
+    if lhs != rhs
+    {
+        LengthMismatch { lhs, rhs }.halt()
+    }
+    Interleave::new(a, b)
+}
+
+/// Distributes the elements of a bulk round-robin into `K` bulks, in a single pass.
+///
+/// See the documentation of [`Bulk::deinterleave`](crate::Bulk::deinterleave) for more.
+///
+/// # Panics
+///
+/// Panics if the length of the bulk is not a multiple of `K`. If the length is known at compile-time,
+/// this is instead a compile-time error.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let a = [1, 2, 3, 4, 5, 6];
+///
+/// let [left, right] = bulks::deinterleave::<_, 2>(a);
+///
+/// let left: [_; _] = left.collect();
+/// let right: [_; _] = right.collect();
+///
+/// assert_eq!(left, [1, 3, 5]);
+/// assert_eq!(right, [2, 4, 6]);
+/// ```
+#[allow(clippy::type_complexity)]
+#[track_caller]
+pub const fn deinterleave<I, const K: usize>(iterable: I) -> [<length::Div<BulkLength<I::IntoBulk>, [(); K]> as UnzipBuffer>::Output<I::Item>; K]
+where
+    I: ~const IntoBulk<IntoBulk: ~const Bulk + DivisibleLength<K>, Item: ~const Destruct>,
+    length::Div<BulkLength<I::IntoBulk>, [(); K]>: ~const UnzipBuffer
+{
+    struct Dealer<'a, L, T, const K: usize>
+    where
+        L: UnzipBuffer + ?Sized
+    {
+        buffers: &'a mut [MaybeUninit<L::Buffer<T>>; K],
+        i: usize
+    }
+    const impl<'a, L, T, const K: usize> FnOnce<(T,)> for Dealer<'a, L, T, K>
+    where
+        L: ~const UnzipBuffer + ?Sized
+    {
+        type Output = ();
+
+        extern "rust-call" fn call_once(mut self, args: (T,)) -> Self::Output
+        {
+            self.call_mut(args)
+        }
+    }
+    const impl<'a, L, T, const K: usize> FnMut<(T,)> for Dealer<'a, L, T, K>
+    where
+        L: ~const UnzipBuffer + ?Sized
+    {
+        extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+        {
+            let Self { buffers, i } = self;
+            L::push(unsafe { buffers[*i].assume_init_mut() }, x);
+            *i = (*i + 1) % K;
+        }
+    }
+
+    let bulk = iterable.into_bulk();
+    let len = bulk.len();
+    assert!(K != 0 && len.is_multiple_of(K), "The length of the bulk must be a multiple of the number of parts.");
+
+    let mut buffers = MaybeUninit::<[_; K]>::uninit().transpose();
+    let mut i = 0;
+    while i < K
+    {
+        buffers[i].write(<length::Div<BulkLength<I::IntoBulk>, [(); K]> as UnzipBuffer>::buffer(len / K));
+        i += 1;
+    }
+
+    bulk.for_each(Dealer::<length::Div<BulkLength<I::IntoBulk>, [(); K]>, _, K> {
+        buffers: &mut buffers,
+        i: 0
+    });
+
+    let mut parts = MaybeUninit::<[_; K]>::uninit().transpose();
+    let mut i = 0;
+    while i < K
+    {
+        parts[i].write(<length::Div<BulkLength<I::IntoBulk>, [(); K]> as UnzipBuffer>::finish(unsafe { buffers[i].assume_init_read() }));
+        i += 1;
+    }
+    unsafe {
+        parts.transpose().assume_init()
+    }
+}
+
+/// Bulks with a length that may be a multiple of `K`.
+///
+/// Implemented for every bulk, unless its length is known at compile-time and is not a multiple of `K`.
+#[rustc_on_unimplemented(
+    message = "the length of bulk `{Self}` is not a multiple of {K}",
+    label = "the bulk can't be split into {K} parts of equal length",
+)]
+pub trait DivisibleLength<const K: usize>: Bulk
+{
+
+}
+impl<B, const K: usize> DivisibleLength<K> for B
+where
+    B: Bulk<MinLength: MultipleOf<B::MaxLength, K>>
+{
+
+}
+
+/// A bulk that alternates between the elements of two bulks of the same length.
+///
+/// This `struct` is created by [`interleave`] or [`Bulk::interleave`].
+/// See their documentation for more.
+#[derive(Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct Interleave<A, B>
+where
+    A: Bulk,
+    B: Bulk<Item = A::Item>
+{
+    a: A,
+    b: B
+}
+
+impl<A, B> Interleave<A, B>
+where
+    A: Bulk,
+    B: Bulk<Item = A::Item>
+{
+    pub(crate) const fn new(a: A, b: B) -> Self
+    {
+        Self { a, b }
+    }
+}
+
+impl<A, B> fmt::Debug for Interleave<A, B>
+where
+    A: Bulk + fmt::Debug,
+    B: Bulk<Item = A::Item> + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("Interleave").field("a", &self.a).field("b", &self.b).finish()
+    }
+}
+
+mod private
+{
+    use array_trait::length::Length;
+
+    pub trait MultipleOf<Max, const K: usize>
+    where
+        Max: ?Sized
+    {
+
+    }
+    impl<const N: usize, const K: usize> MultipleOf<[(); N], K> for [(); N]
+    where
+        Divisible<{N.is_multiple_of(K)}>: True
+    {
+
+    }
+    impl<Min, const K: usize> MultipleOf<[()], K> for Min
+    where
+        Min: Length<Elem = ()> + ?Sized
+    {
+
+    }
+
+    pub struct Divisible<const DIVISIBLE: bool>;
+
+    pub trait True
+    {
+
+    }
+    impl True for Divisible<true>
+    {
+
+    }
+
+    pub struct IntoIter<A, B>
+    where
+        A: Iterator,
+        B: Iterator<Item = A::Item>
+    {
+        pub pairs: core::iter::Zip<A, B>,
+        pub front: Option<B::Item>,
+        pub back: Option<A::Item>
+    }
+
+    impl<A, B> Iterator for IntoIter<A, B>
+    where
+        A: Iterator,
+        B: Iterator<Item = A::Item>
+    {
+        type Item = A::Item;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let Self { pairs, front, back } = self;
+            if let Some(b) = front.take()
+            {
+                return Some(b)
+            }
+            if let Some((a, b)) = pairs.next()
+            {
+                *front = Some(b);
+                return Some(a)
+            }
+            back.take()
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let Self { pairs, front, back } = self;
+            let (min, max) = pairs.size_hint();
+            let extra = front.is_some() as usize + back.is_some() as usize;
+            (
+                min.saturating_mul(2).saturating_add(extra),
+                max.and_then(|max| max.checked_mul(2)?.checked_add(extra))
+            )
+        }
+    }
+    impl<A, B> ExactSizeIterator for IntoIter<A, B>
+    where
+        A: ExactSizeIterator,
+        B: ExactSizeIterator<Item = A::Item>
+    {
+        fn len(&self) -> usize
+        {
+            let Self { pairs, front, back } = self;
+            pairs.len()*2 + front.is_some() as usize + back.is_some() as usize
+        }
+    }
+    impl<A, B> DoubleEndedIterator for IntoIter<A, B>
+    where
+        A: DoubleEndedIterator + ExactSizeIterator,
+        B: DoubleEndedIterator<Item = A::Item> + ExactSizeIterator
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            let Self { pairs, front, back } = self;
+            if let Some(a) = back.take()
+            {
+                return Some(a)
+            }
+            if let Some((a, b)) = pairs.next_back()
+            {
+                *back = Some(a);
+                return Some(b)
+            }
+            front.take()
+        }
+    }
+}
+
+impl<A, B> IntoIterator for Interleave<A, B>
+where
+    A: Bulk,
+    B: Bulk<Item = A::Item>
+{
+    type Item = A::Item;
+    type IntoIter = private::IntoIter<A::IntoIter, B::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { a, b } = self;
+        private::IntoIter {
+            pairs: a.into_iter().zip(b),
+            front: None,
+            back: None
+        }
+    }
+}
+impl<A, B> Bulk for Interleave<A, B>
+where
+    A: Bulk,
+    B: Bulk<Item = A::Item>
+{
+    type MinLength = length::Mul<length::Min<A::MinLength, B::MinLength>, [(); 2]>;
+    type MaxLength = length::Mul<length::Min<A::MaxLength, B::MaxLength>, [(); 2]>;
+
+    fn len(&self) -> usize
+    {
+        let Self { a, b } = self;
+        Ord::min(a.len(), b.len())*2
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { a, b } = self;
+        a.is_empty() || b.is_empty()
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        self.into_iter().try_for_each(f)
+    }
+}
+impl<A, B> DoubleEndedBulk for Interleave<A, B>
+where
+    A: DoubleEndedBulk,
+    B: DoubleEndedBulk<Item = A::Item>
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().rev().for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        self.into_iter().rev().try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let left = [1, 3, 5];
+        let right = [2, 4, 6];
+
+        let interleaved: [_; _] = left.into_bulk()
+            .interleave(right)
+            .collect();
+        assert_eq!(interleaved, [1, 2, 3, 4, 5, 6]);
+
+        let [l, r] = interleaved.into_bulk()
+            .deinterleave::<2>();
+        let l: [_; _] = l.collect();
+        let r: [_; _] = r.collect();
+        assert_eq!(l, left);
+        assert_eq!(r, right);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn rev()
+    {
+        let left = vec![1, 3, 5];
+        let right = vec![2, 4, 6];
+
+        let interleaved: Vec<_> = left.into_bulk()
+            .interleave(right)
+            .rev()
+            .collect();
+        assert_eq!(interleaved, [6, 5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn bounded()
+    {
+        let a = [1, 2, 3, 4, 5, 6];
+        let b: &[_] = &[7, 8, 9];
+
+        let c: Vec<_> = b.bulk()
+            .interleave(a.bulk().filter(|&&x| x % 2 == 0))
+            .copied()
+            .collect();
+        assert_eq!(c, [7, 2, 8, 4, 9, 6]);
+
+        let d: Vec<_> = a.bulk()
+            .filter(|&&x| x % 2 == 0)
+            .interleave(b)
+            .copied()
+            .collect();
+        assert_eq!(d, [2, 7, 4, 8, 6, 9]);
+    }
+
+    #[test]
+    fn deinterleave_three()
+    {
+        let a = [1, 2, 3, 4, 5, 6];
+
+        let [x, y, z] = a.into_bulk()
+            .deinterleave::<3>();
+        let x: [_; _] = x.collect();
+        let y: [_; _] = y.collect();
+        let z: [_; _] = z.collect();
+
+        assert_eq!(x, [1, 4]);
+        assert_eq!(y, [2, 5]);
+        assert_eq!(z, [3, 6]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatch()
+    {
+        let a: &[_] = &[1, 2, 3];
+        let b: &[_] = &[4, 5];
+
+        let _ = crate::interleave(a, b);
+    }
+}
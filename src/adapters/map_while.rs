@@ -0,0 +1,114 @@
+use core::fmt;
+
+use crate::{Bulk, util::Counted};
+
+/// A bulk that only yields elements mapped by `f` while it returns [`Some`].
+///
+/// This `struct` is created by the [`map_while`](Bulk::map_while) method on [`Bulk`]. See its
+/// documentation for more.
+///
+/// # Notes about length
+///
+/// The length of a [`MapWhile`] can't be known without evaluating `f`, so iterating it only gives an upper bound
+/// as its size hint. [`len`](Bulk::len) is found by running `f` on a clone of the bulk, so any side effects of `f`
+/// will happen again every time the length is needed.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct MapWhile<I, F>
+where
+    I: Bulk
+{
+    bulk: I,
+    f: F
+}
+
+impl<I, F> MapWhile<I, F>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, f: F) -> Self
+    {
+        Self {
+            bulk,
+            f
+        }
+    }
+}
+
+impl<I, F> fmt::Debug for MapWhile<I, F>
+where
+    I: Bulk + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, f: _ } = self;
+        f.debug_struct("MapWhile").field("bulk", bulk).finish()
+    }
+}
+
+impl<I, F, B> IntoIterator for MapWhile<I, F>
+where
+    I: Bulk,
+    F: FnMut(I::Item) -> Option<B>
+{
+    type Item = B;
+    type IntoIter = Counted<core::iter::MapWhile<I::IntoIter, F>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, f } = self;
+        Counted::new(bulk.into_iter().map_while(f))
+    }
+}
+impl<I, F, B> Bulk for MapWhile<I, F>
+where
+    I: Bulk<IntoIter: Clone> + Clone,
+    F: FnMut(I::Item) -> Option<B> + Clone
+{
+    type MinLength = [(); 0];
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        self.clone()
+            .into_iter()
+            .count()
+    }
+
+    fn for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: FnMut(Self::Item)
+    {
+        let Self { bulk, f: map_while } = self;
+        bulk.into_iter().map_while(map_while).for_each(f)
+    }
+    fn try_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        FF: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        let Self { bulk, f: map_while } = self;
+        bulk.into_iter().map_while(map_while).try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [-1, 4, 0, 1];
+
+        let bulk = a.into_bulk()
+            .map_while(|x| 16i32.checked_div(x));
+        assert_eq!(bulk.len(), 2);
+
+        let b: Vec<_> = bulk.collect();
+        assert_eq!(b, [-16, 4]);
+    }
+}
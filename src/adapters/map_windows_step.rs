@@ -0,0 +1,545 @@
+use core::{fmt, marker::Destruct, ops::Try};
+
+use array_trait::length;
+
+use crate::{Bulk, DoubleEndedBulk, util::ArrayBuffer};
+
+/// A bulk over the mapped windows of another bulk, advancing `H` elements between each window.
+///
+/// This `struct` is created by the [`Bulk::map_windows_step`]. See its
+/// documentation for more information.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct MapWindowsStep<I, F, const N: usize, const H: usize>
+where
+    I: Bulk,
+    F: for<'a> FnMut<(&'a [I::Item; N],)>
+{
+    bulk: I,
+    f: F,
+}
+
+impl<I: Bulk, F, U, const N: usize, const H: usize> MapWindowsStep<I, F, N, H>
+where
+    I: Bulk,
+    F: FnMut(&[I::Item; N]) -> U
+{
+    pub(crate) const fn new(bulk: I, f: F) -> Self
+    {
+        assert!(N != 0, "array in `Bulk::map_windows_step` must contain more than 0 elements");
+        assert!(H != 0, "step in `Bulk::map_windows_step` must be non-zero");
+
+        // Only ZST arrays' length can be so large.
+        if core::mem::size_of::<I::Item>() != 0
+        {
+            assert!(
+                N.checked_mul(2).is_some(),
+                "array size of `Bulk::map_windows_step` is too large"
+            );
+        }
+
+        Self {
+            bulk,
+            f
+        }
+    }
+
+    const fn windows(len: usize) -> usize
+    {
+        if len < N
+        {
+            0
+        }
+        else
+        {
+            (len - N)/H + 1
+        }
+    }
+
+    /// The number of elements to skip at the end of the bulk, so that the last window lines up with the first one.
+    const fn tail(len: usize) -> usize
+    {
+        if len < N
+        {
+            0
+        }
+        else
+        {
+            (len - N) % H
+        }
+    }
+}
+
+impl<I, F, const N: usize, const H: usize> fmt::Debug for MapWindowsStep<I, F, N, H>
+where
+    I: Bulk + fmt::Debug,
+    F: for<'a> FnMut<(&'a [I::Item; N],)>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("MapWindowsStep").field("bulk", &self.bulk).finish()
+    }
+}
+
+mod private
+{
+    use core::{mem::MaybeUninit, ops::Range, ptr};
+
+    /// Windows are kept in a buffer of `2*N` elements. The elements taken from the front are right-aligned in the first half,
+    /// and the elements taken from the back are left-aligned in the second half. Once the inner iterator is exhausted,
+    /// the remaining elements are contiguous, and the remaining windows are read directly from the buffer.
+    pub struct IntoIter<I, F, const N: usize, const H: usize>
+    where
+        I: Iterator
+    {
+        pub iter: I,
+        pub f: F,
+        pub buffer: [[MaybeUninit<I::Item>; N]; 2],
+        pub front_len: usize,
+        pub back_len: usize,
+        pub front_skip: usize,
+        pub back_skip: usize,
+        pub shared: Option<Range<usize>>,
+        pub remaining: usize
+    }
+
+    /// Appends `value` to the elements right-aligned at `end`, dropping the first one if full.
+    ///
+    /// Returns `true` if an element was dropped.
+    unsafe fn push_front<T, const N: usize>(end: *mut T, len: &mut usize, value: T) -> bool
+    {
+        unsafe {
+            let start = end.sub(*len);
+            let full = *len == N;
+            if full
+            {
+                ptr::drop_in_place(start);
+                ptr::copy(start.add(1), start, N - 1);
+            }
+            else
+            {
+                ptr::copy(start, start.sub(1), *len);
+                *len += 1;
+            }
+            end.sub(1).write(value);
+            full
+        }
+    }
+
+    /// Prepends `value` to the elements left-aligned at `start`, dropping the last one if full.
+    ///
+    /// Returns `true` if an element was dropped.
+    unsafe fn push_back<T, const N: usize>(start: *mut T, len: &mut usize, value: T) -> bool
+    {
+        unsafe {
+            let full = *len == N;
+            if full
+            {
+                ptr::drop_in_place(start.add(N - 1));
+                ptr::copy(start, start.add(1), N - 1);
+            }
+            else
+            {
+                ptr::copy(start, start.add(1), *len);
+                *len += 1;
+            }
+            start.write(value);
+            full
+        }
+    }
+
+    impl<I, F, const N: usize, const H: usize> IntoIter<I, F, N, H>
+    where
+        I: Iterator
+    {
+        fn share(&mut self)
+        {
+            let Self { front_len, back_len, front_skip, back_skip, shared, .. } = self;
+            *shared = Some(Range {
+                start: N - *front_len + *front_skip,
+                end: N + *back_len - *back_skip
+            })
+        }
+    }
+
+    impl<I, F, U, const N: usize, const H: usize> Iterator for IntoIter<I, F, N, H>
+    where
+        I: Iterator,
+        F: FnMut(&[I::Item; N]) -> U
+    {
+        type Item = U;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            if self.remaining == 0
+            {
+                return None
+            }
+            loop
+            {
+                let base = self.buffer.as_mut_ptr().cast::<I::Item>();
+                let Self { iter, f, front_len, front_skip, shared, remaining, .. } = self;
+                if let Some(Range { start, end: _ }) = shared
+                {
+                    let window = unsafe {
+                        &*base.add(*start).cast::<[I::Item; N]>()
+                    };
+                    *start += H;
+                    *remaining -= 1;
+                    return Some(f(window))
+                }
+                if *front_len == N && *front_skip == 0
+                {
+                    let window = unsafe {
+                        &*base.cast::<[I::Item; N]>()
+                    };
+                    *front_skip = H;
+                    *remaining -= 1;
+                    return Some(f(window))
+                }
+                match iter.next()
+                {
+                    Some(x) => if unsafe { push_front::<_, N>(base.add(N), front_len, x) } && *front_skip > 0
+                    {
+                        *front_skip -= 1
+                    },
+                    None => self.share()
+                }
+            }
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            (self.remaining, Some(self.remaining))
+        }
+    }
+    impl<I, F, U, const N: usize, const H: usize> ExactSizeIterator for IntoIter<I, F, N, H>
+    where
+        I: Iterator,
+        F: FnMut(&[I::Item; N]) -> U
+    {
+        fn len(&self) -> usize
+        {
+            self.remaining
+        }
+    }
+    impl<I, F, U, const N: usize, const H: usize> DoubleEndedIterator for IntoIter<I, F, N, H>
+    where
+        I: DoubleEndedIterator,
+        F: FnMut(&[I::Item; N]) -> U
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            if self.remaining == 0
+            {
+                return None
+            }
+            loop
+            {
+                let base = self.buffer.as_mut_ptr().cast::<I::Item>();
+                let Self { iter, f, back_len, back_skip, shared, remaining, .. } = self;
+                if let Some(Range { start: _, end }) = shared
+                {
+                    let window = unsafe {
+                        &*base.add(*end - N).cast::<[I::Item; N]>()
+                    };
+                    *end -= H;
+                    *remaining -= 1;
+                    return Some(f(window))
+                }
+                if *back_len == N && *back_skip == 0
+                {
+                    let window = unsafe {
+                        &*base.add(N).cast::<[I::Item; N]>()
+                    };
+                    *back_skip = H;
+                    *remaining -= 1;
+                    return Some(f(window))
+                }
+                match iter.next_back()
+                {
+                    Some(x) => if unsafe { push_back::<_, N>(base.add(N), back_len, x) } && *back_skip > 0
+                    {
+                        *back_skip -= 1
+                    },
+                    None => self.share()
+                }
+            }
+        }
+    }
+    impl<I, F, const N: usize, const H: usize> Drop for IntoIter<I, F, N, H>
+    where
+        I: Iterator
+    {
+        fn drop(&mut self)
+        {
+            let base = self.buffer.as_mut_ptr().cast::<I::Item>();
+            let Self { front_len, back_len, .. } = self;
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(base.add(N - *front_len), *front_len + *back_len))
+            }
+        }
+    }
+}
+
+impl<I: Bulk, F, U, const N: usize, const H: usize> IntoIterator for MapWindowsStep<I, F, N, H>
+where
+    I: Bulk,
+    F: FnMut(&[I::Item; N]) -> U
+{
+    type Item = U;
+    type IntoIter = private::IntoIter<I::IntoIter, F, N, H>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, f } = self;
+        let len = bulk.len();
+        private::IntoIter {
+            iter: bulk.into_iter(),
+            f,
+            buffer: core::mem::MaybeUninit::uninit().transpose().map(core::mem::MaybeUninit::transpose),
+            front_len: 0,
+            back_len: 0,
+            front_skip: 0,
+            back_skip: Self::tail(len),
+            shared: None,
+            remaining: Self::windows(len)
+        }
+    }
+}
+
+const impl<I: Bulk, F, U, const N: usize, const H: usize> Bulk for MapWindowsStep<I, F, N, H>
+where
+    I: ~const Bulk<Item: ~const Destruct>,
+    F: ~const FnMut(&[I::Item; N]) -> U + ~const Destruct
+{
+    type MinLength = length::DivCeil<length::Windowed<I::MinLength, [(); N]>, [(); H]>;
+    type MaxLength = length::DivCeil<length::Windowed<I::MaxLength, [(); N]>, [(); H]>;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, f: _ } = self;
+        Self::windows(bulk.len())
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { bulk, f: _ } = self;
+        bulk.len() < N
+    }
+
+    fn for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, f: map } = self;
+        bulk.for_each(Closure::<_, _, _, _, N, H, false> {
+            map,
+            f,
+            buffer: ArrayBuffer::new(),
+            skip: 0
+        });
+    }
+    fn try_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, f: map } = self;
+        bulk.try_for_each(TryClosure::<_, _, _, _, _, N, H, false> {
+            map,
+            f,
+            buffer: ArrayBuffer::new(),
+            skip: 0
+        })
+    }
+}
+const impl<I: Bulk, F, U, const N: usize, const H: usize> DoubleEndedBulk for MapWindowsStep<I, F, N, H>
+where
+    I: ~const DoubleEndedBulk<Item: ~const Destruct>,
+    F: ~const FnMut(&[I::Item; N]) -> U + ~const Destruct
+{
+    fn rev_for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, f: map } = self;
+        let skip = Self::tail(bulk.len());
+        bulk.rev_for_each(Closure::<_, _, _, _, N, H, true> {
+            map,
+            f,
+            buffer: ArrayBuffer::new(),
+            skip
+        });
+    }
+    fn try_rev_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, f: map } = self;
+        let skip = Self::tail(bulk.len());
+        bulk.try_rev_for_each(TryClosure::<_, _, _, _, _, N, H, true> {
+            map,
+            f,
+            buffer: ArrayBuffer::new(),
+            skip
+        })
+    }
+}
+
+struct Closure<F, FF, T, U, const N: usize, const H: usize, const REV: bool>
+where
+    F: FnMut(&[T; N]) -> U,
+    FF: FnMut(U)
+{
+    map: F,
+    f: FF,
+    buffer: ArrayBuffer<T, N, REV>,
+    skip: usize
+}
+const impl<F, FF, T, U, const N: usize, const H: usize, const REV: bool> FnOnce<(T,)> for Closure<F, FF, T, U, N, H, REV>
+where
+    T: ~const Destruct,
+    F: ~const FnMut(&[T; N]) -> U + ~const Destruct,
+    FF: ~const FnMut(U) + ~const Destruct
+{
+    type Output = ();
+
+    extern "rust-call" fn call_once(mut self, args: (T,)) -> Self::Output
+    {
+        self.call_mut(args)
+    }
+}
+const impl<F, FF, T, U, const N: usize, const H: usize, const REV: bool> FnMut<(T,)> for Closure<F, FF, T, U, N, H, REV>
+where
+    T: ~const Destruct,
+    F: ~const FnMut(&[T; N]) -> U,
+    FF: ~const FnMut(U)
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+    {
+        let Self { map, f, buffer, skip } = self;
+        if buffer.push_out(x).is_some() && *skip > 0
+        {
+            *skip -= 1
+        }
+        if *skip == 0 && let Some(window) = buffer.as_array()
+        {
+            *skip = H;
+            f(map(window))
+        }
+    }
+}
+
+struct TryClosure<F, FF, T, U, R, const N: usize, const H: usize, const REV: bool>
+where
+    F: FnMut(&[T; N]) -> U,
+    FF: FnMut(U) -> R
+{
+    map: F,
+    f: FF,
+    buffer: ArrayBuffer<T, N, REV>,
+    skip: usize
+}
+const impl<F, FF, T, U, R, const N: usize, const H: usize, const REV: bool> FnOnce<(T,)> for TryClosure<F, FF, T, U, R, N, H, REV>
+where
+    T: ~const Destruct,
+    F: ~const FnMut(&[T; N]) -> U + ~const Destruct,
+    FF: ~const FnMut(U) -> R + ~const Destruct,
+    R: ~const Try<Output = (), Residual: ~const Destruct>
+{
+    type Output = R;
+
+    extern "rust-call" fn call_once(mut self, args: (T,)) -> Self::Output
+    {
+        self.call_mut(args)
+    }
+}
+const impl<F, FF, T, U, R, const N: usize, const H: usize, const REV: bool> FnMut<(T,)> for TryClosure<F, FF, T, U, R, N, H, REV>
+where
+    T: ~const Destruct,
+    F: ~const FnMut(&[T; N]) -> U,
+    FF: ~const FnMut(U) -> R,
+    R: ~const Try<Output = (), Residual: ~const Destruct>
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+    {
+        let Self { map, f, buffer, skip } = self;
+        if buffer.push_out(x).is_some() && *skip > 0
+        {
+            *skip -= 1
+        }
+        if *skip == 0 && let Some(window) = buffer.as_array()
+        {
+            *skip = H;
+            f(map(window))?
+        }
+        R::from_output(())
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7, 8];
+
+        let frames: [_; 3] = a.into_bulk()
+            .map_windows_step::<_, _, 4, 2>(|&w| w)
+            .collect();
+        assert_eq!(frames, [[1, 2, 3, 4], [3, 4, 5, 6], [5, 6, 7, 8]]);
+
+        let b: [_; 2] = a.into_bulk()
+            .map_windows_step::<_, _, 3, 3>(|&[x, y, z]| x + y + z)
+            .rev()
+            .collect();
+        assert_eq!(b, [4 + 5 + 6, 1 + 2 + 3]);
+    }
+
+    #[test]
+    fn iter()
+    {
+        let a = vec![1, 2, 3, 4, 5];
+
+        let mut iter = a.into_bulk()
+            .map_windows_step::<_, _, 3, 1>(|&w| w)
+            .into_iter();
+
+        assert_eq!(iter.len(), 3);
+        assert_eq!(iter.next(), Some([1, 2, 3]));
+        assert_eq!(iter.next_back(), Some([3, 4, 5]));
+        assert_eq!(iter.next(), Some([2, 3, 4]));
+        assert_eq!(iter.next_back(), None);
+
+        let b: Vec<_> = [1, 2, 3, 4, 5, 6].into_bulk()
+            .map_windows_step::<_, _, 2, 3>(|&w| w)
+            .into_iter()
+            .rev()
+            .collect();
+        assert_eq!(b, [[4, 5], [1, 2]]);
+    }
+
+    #[test]
+    fn drops()
+    {
+        use std::rc::Rc;
+
+        let x = Rc::new(());
+        let a = [(); 7].map(|()| x.clone());
+
+        let mut iter = a.into_bulk()
+            .map_windows_step::<_, _, 3, 2>(|_| ())
+            .into_iter();
+        assert_eq!(iter.next(), Some(()));
+        assert_eq!(iter.next_back(), Some(()));
+
+        core::mem::drop(iter);
+        assert_eq!(Rc::strong_count(&x), 1);
+    }
+}
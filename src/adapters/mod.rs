@@ -7,33 +7,52 @@ moddef::moddef!(
         array_chunks_with_remainder
     },
     flat(pub) mod {
+        accumulate_exclusive,
+        accumulate,
         array_chunks,
+        cartesian_product,
         chain,
         cloned,
         copied,
+        cycle_n,
         empty,
         enumerate_from,
         enumerate,
+        filter_map,
+        filter,
         flat_map,
         flatten,
         contained,
         inspect,
+        interleave,
         intersperse_with,
         intersperse,
+        map_windows_step,
         map_windows,
+        map_while,
         map,
+        multi_zip,
         mutate,
         once_with,
         once,
+        rarray_chunks,
         merge,
         repeat_n_with,
         repeat_n,
         resize_with,
         resize,
         rev,
+        scan,
+        skip_last,
+        skip_while,
         skip,
         step_by,
+        take_last,
+        take_while,
         take,
+        transpose,
+        zip_exact,
+        zip_longest,
         zip
     }
 );
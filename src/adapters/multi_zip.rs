@@ -0,0 +1,371 @@
+use core::fmt;
+
+use array_trait::length::{self, LengthValue};
+
+use crate::{Bulk, DoubleEndedBulk, SplitBulk};
+
+/// Converts the members of a tuple to bulks and zips them all together.
+///
+/// Unlike nesting [`zip`](crate::zip), this yields flat tuples. The first member must be convertible into a bulk,
+/// while the rest may be any iterables, as long as they are at least as long as the resulting bulk.
+///
+/// See the documentation of [`Bulk::zip`](crate::Bulk::zip) for more.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let xs = [1, 2, 3];
+/// let ys = [4, 5, 6];
+/// let zs = [7, 8, 9];
+///
+/// let bulk = bulks::multizip((xs, ys, zs));
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [(1, 4, 7), (2, 5, 8), (3, 6, 9)]);
+/// ```
+///
+/// The length is the shortest of the members' lengths:
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let xs = [1, 2, 3, 4];
+/// let ys = ['a', 'b', 'c'];
+///
+/// let zs = [true, false, true, false, true];
+///
+/// let bulk = bulks::multizip((xs, ys, zs));
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [(1, 'a', true), (2, 'b', false), (3, 'c', true)]);
+///
+/// // Any iterable may be used after the first member:
+/// let bulk = bulks::multizip((xs, ys, 0..));
+///
+/// let s: Vec<_> = bulk.collect();
+/// assert_eq!(s, [(1, 'a', 0), (2, 'b', 1), (3, 'c', 2)]);
+/// ```
+pub const fn multizip<T>(bulks: T) -> MultiZip<T::IntoBulks>
+where
+    T: ~const IntoMultiZip
+{
+    MultiZip::new(bulks.into_bulks())
+}
+
+pub(crate) use private::IntoMultiZip as IntoMultiZip;
+
+/// A bulk that operates on several other bulks simultaneously, yielding flat tuples.
+///
+/// This `struct` is created by [`multizip`]. See its documentation for more.
+#[derive(Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct MultiZip<T>
+{
+    bulks: T
+}
+
+impl<T> MultiZip<T>
+{
+    pub(crate) const fn new(bulks: T) -> MultiZip<T>
+    {
+        Self { bulks }
+    }
+}
+
+impl<T> fmt::Debug for MultiZip<T>
+where
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulks } = self;
+        f.debug_struct("MultiZip").field("bulks", bulks).finish()
+    }
+}
+
+mod private
+{
+    use crate::{IntoBulk, IntoContainedBy};
+
+    #[rustc_on_unimplemented(
+        message = "value of type `{Self}` cannot be zipped in bulk",
+        label = "expected a tuple of two to eight values, where the first can be converted into a bulk",
+    )]
+    pub const trait IntoMultiZip
+    {
+        type IntoBulks;
+
+        fn into_bulks(self) -> Self::IntoBulks;
+    }
+
+    pub struct IntoIter<T>
+    {
+        pub iters: T
+    }
+
+    macro_rules! impl_into_multi_zip {
+        ($a0:ident $x0:ident; $($a:ident $x:ident),+) => {
+            const impl<$a0, $($a,)+> IntoMultiZip for ($a0, $($a,)+)
+            where
+                $a0: ~const IntoBulk,
+                $($a: ~const IntoContainedBy<$a0>,)+
+            {
+                type IntoBulks = ($a0::IntoBulk, $(<$a::IntoContained as IntoBulk>::IntoBulk,)+);
+
+                fn into_bulks(self) -> Self::IntoBulks
+                {
+                    let ($x0, $($x,)+) = self;
+                    unsafe {
+                        (
+                            $x0.into_bulk(),
+                            $($x.into_contained().into_bulk(),)+
+                        )
+                    }
+                }
+            }
+        };
+    }
+
+    impl_into_multi_zip!(A0 a0; A1 a1);
+    impl_into_multi_zip!(A0 a0; A1 a1, A2 a2);
+    impl_into_multi_zip!(A0 a0; A1 a1, A2 a2, A3 a3);
+    impl_into_multi_zip!(A0 a0; A1 a1, A2 a2, A3 a3, A4 a4);
+    impl_into_multi_zip!(A0 a0; A1 a1, A2 a2, A3 a3, A4 a4, A5 a5);
+    impl_into_multi_zip!(A0 a0; A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6);
+    impl_into_multi_zip!(A0 a0; A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7);
+}
+
+macro_rules! min_length {
+    ($l:ty) => {
+        $l
+    };
+    ($l:ty, $($r:ty),+) => {
+        length::Min<$l, min_length!($($r),+)>
+    };
+}
+
+macro_rules! impl_multi_zip {
+    ($($a:ident $x:ident),+) => {
+        impl<$($a,)+> Iterator for private::IntoIter<($($a,)+)>
+        where
+            $($a: Iterator,)+
+        {
+            type Item = ($($a::Item,)+);
+
+            fn next(&mut self) -> Option<Self::Item>
+            {
+                let Self { iters: ($($x,)+) } = self;
+                Some(($($x.next()?,)+))
+            }
+            fn size_hint(&self) -> (usize, Option<usize>)
+            {
+                let Self { iters: ($($x,)+) } = self;
+                let mut min = usize::MAX;
+                let mut max = None;
+                $(
+                    let (lower, upper) = $x.size_hint();
+                    min = Ord::min(min, lower);
+                    max = match (max, upper)
+                    {
+                        (Some(max), Some(upper)) => Some(Ord::min(max, upper)),
+                        (max, None) => max,
+                        (None, upper) => upper
+                    };
+                )+
+                (min, max)
+            }
+        }
+        impl<$($a,)+> ExactSizeIterator for private::IntoIter<($($a,)+)>
+        where
+            $($a: ExactSizeIterator,)+
+        {
+            fn len(&self) -> usize
+            {
+                let Self { iters: ($($x,)+) } = self;
+                let mut len = usize::MAX;
+                $(len = Ord::min(len, $x.len());)+
+                len
+            }
+        }
+        impl<$($a,)+> DoubleEndedIterator for private::IntoIter<($($a,)+)>
+        where
+            $($a: DoubleEndedIterator + ExactSizeIterator,)+
+        {
+            fn next_back(&mut self) -> Option<Self::Item>
+            {
+                let len = self.len();
+                let Self { iters: ($($x,)+) } = self;
+                $(let _ = $x.advance_back_by($x.len() - len);)+
+                Some(($($x.next_back()?,)+))
+            }
+        }
+
+        /*const*/ impl<$($a,)+> IntoIterator for MultiZip<($($a,)+)>
+        where
+            $($a: Bulk,)+
+        {
+            type Item = ($($a::Item,)+);
+            type IntoIter = private::IntoIter<($($a::IntoIter,)+)>;
+
+            fn into_iter(self) -> Self::IntoIter
+            {
+                let Self { bulks: ($($x,)+) } = self;
+                private::IntoIter {
+                    iters: ($($x.into_iter(),)+)
+                }
+            }
+        }
+        /*const*/ impl<$($a,)+> Bulk for MultiZip<($($a,)+)>
+        where
+            $($a: Bulk,)+
+        {
+            type MinLength = min_length!($($a::MinLength),+);
+            type MaxLength = min_length!($($a::MaxLength),+);
+
+            fn len(&self) -> usize
+            {
+                let Self { bulks: ($($x,)+) } = self;
+                let mut len = usize::MAX;
+                $(len = Ord::min(len, $x.len());)+
+                len
+            }
+            fn is_empty(&self) -> bool
+            {
+                let Self { bulks: ($($x,)+) } = self;
+                $($x.is_empty())||+
+            }
+
+            fn first(self) -> Option<Self::Item>
+            where
+                Self: Sized
+            {
+                let Self { bulks: ($($x,)+) } = self;
+                $(let $x = $x.first();)+
+                Some(($($x?,)+))
+            }
+
+            fn for_each<F>(self, f: F)
+            where
+                Self: Sized,
+                F: FnMut(Self::Item)
+            {
+                self.into_iter().for_each(f)
+            }
+            fn try_for_each<F, R>(self, f: F) -> R
+            where
+                Self: Sized,
+                F: FnMut(Self::Item) -> R,
+                R: core::ops::Try<Output = ()>
+            {
+                self.into_iter().try_for_each(f)
+            }
+        }
+        /*const*/ impl<$($a,)+> DoubleEndedBulk for MultiZip<($($a,)+)>
+        where
+            $($a: DoubleEndedBulk,)+
+        {
+            fn rev_for_each<F>(self, f: F)
+            where
+                Self: Sized,
+                F: FnMut(Self::Item)
+            {
+                self.into_iter().rev().for_each(f)
+            }
+            fn try_rev_for_each<F, R>(self, f: F) -> R
+            where
+                Self: Sized,
+                F: FnMut(Self::Item) -> R,
+                R: core::ops::Try<Output = ()>
+            {
+                self.into_iter().rev().try_for_each(f)
+            }
+        }
+        const impl<$($a,)+ L> SplitBulk<L> for MultiZip<($($a,)+)>
+        where
+            $($a: ~const SplitBulk<L, Left: ~const Bulk, Right: ~const Bulk>,)+
+            Self: ~const Bulk,
+            MultiZip<($($a::Left,)+)>: ~const Bulk<Item = Self::Item>,
+            MultiZip<($($a::Right,)+)>: ~const Bulk<Item = Self::Item>,
+            L: LengthValue
+        {
+            type Left = MultiZip<($($a::Left,)+)>;
+            type Right = MultiZip<($($a::Right,)+)>;
+
+            fn split_at(Self { bulks: ($($x,)+) }: Self, n: L) -> (Self::Left, Self::Right)
+            where
+                Self: Sized
+            {
+                $(let $x = $x.split_at(n);)+
+
+                (
+                    MultiZip::new(($($x.0,)+)),
+                    MultiZip::new(($($x.1,)+))
+                )
+            }
+        }
+    };
+}
+
+impl_multi_zip!(A0 a0, A1 a1);
+impl_multi_zip!(A0 a0, A1 a1, A2 a2);
+impl_multi_zip!(A0 a0, A1 a1, A2 a2, A3 a3);
+impl_multi_zip!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4);
+impl_multi_zip!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5);
+impl_multi_zip!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6);
+impl_multi_zip!(A0 a0, A1 a1, A2 a2, A3 a3, A4 a4, A5 a5, A6 a6, A7 a7);
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3];
+        let b = [4, 5, 6];
+        let c = [7, 8, 9];
+
+        let sums: [_; _] = crate::multizip((a, b, c))
+            .map(|(a, b, c)| a + b + c)
+            .collect();
+
+        assert_eq!(sums, [12, 15, 18]);
+    }
+
+    #[test]
+    fn rev()
+    {
+        let a = [1, 2, 3, 4];
+        let b = ['a', 'b', 'c'];
+
+        let c = ['x', 'y', 'z', 'w', 'v'];
+
+        let d: [_; _] = crate::multizip((a, b, c))
+            .rev()
+            .collect();
+
+        assert_eq!(d, [(3, 'c', 'z'), (2, 'b', 'y'), (1, 'a', 'x')]);
+    }
+
+    #[test]
+    fn split()
+    {
+        let a = [1, 2, 3, 4];
+        let b = [5, 6, 7, 8];
+        let c = [9, 10, 11, 12];
+
+        let (left, right) = crate::multizip((a, b, c))
+            .split_at([(); 1]);
+
+        let left: [_; _] = left.collect();
+        let right: [_; _] = right.collect();
+
+        assert_eq!(left, [(1, 5, 9)]);
+        assert_eq!(right, [(2, 6, 10), (3, 7, 11), (4, 8, 12)]);
+    }
+}
@@ -0,0 +1,186 @@
+use core::{marker::Destruct, ops::Try};
+
+use array_trait::length;
+
+use crate::{Bulk, DoubleEndedBulk, IntoBulk, Rev, adapters::array_chunks_with_remainder::ArrayChunksWithRemainder, util::ArrayBuffer};
+
+/// A bulk over `N` elements of the bulk at a time, aligned to the end of the bulk.
+///
+/// The chunks do not overlap. If `N` does not divide the length of the
+/// bulk, then the first up to `N-1` elements will be omitted.
+///
+/// This `struct` is created by the [`rarray_chunks`][Bulk::rarray_chunks]
+/// method on [`Bulk`]. See its documentation for more.
+#[derive(Debug, Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct RArrayChunks<I, const N: usize>
+where
+    I: Bulk
+{
+    bulk: I
+}
+
+impl<I, const N: usize> RArrayChunks<I, N>
+where
+    I: Bulk
+{
+    #[track_caller]
+    pub(crate) const fn new(bulk: I) -> Self
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        Self {
+            bulk
+        }
+    }
+}
+
+/*const*/ impl<I, const N: usize> IntoIterator for RArrayChunks<I, N>
+where
+    I: Bulk
+{
+    type Item = [I::Item; N];
+    type IntoIter = core::iter::ArrayChunks<core::iter::Skip<I::IntoIter>, N>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self {bulk} = self;
+        let skip = bulk.len() % N;
+        bulk.into_iter().skip(skip).array_chunks()
+    }
+}
+const impl<I, const N: usize> Bulk for RArrayChunks<I, N>
+where
+    I: ~const Bulk<Item: ~const Destruct>,
+{
+    type MinLength = length::Div<I::MinLength, [(); N]>;
+    type MaxLength = length::Div<I::MaxLength, [(); N]>;
+
+    #[inline]
+    fn len(&self) -> usize
+    {
+        let Self {bulk} = self;
+        bulk.len()/N
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self {bulk} = self;
+        let skip = bulk.len() % N;
+        let mut remainder = ArrayBuffer::<_, _, false>::new();
+        let bulk = ArrayChunksWithRemainder::new_with_skip(bulk, &mut remainder, skip);
+        bulk.for_each(f);
+    }
+
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self {bulk} = self;
+        let skip = bulk.len() % N;
+        let mut remainder = ArrayBuffer::<_, _, false>::new();
+        let bulk = ArrayChunksWithRemainder::new_with_skip(bulk, &mut remainder, skip);
+        bulk.try_for_each(f)?;
+        Try::from_output(())
+    }
+}
+const impl<I, const N: usize> DoubleEndedBulk for RArrayChunks<I, N>
+where
+    I: ~const DoubleEndedBulk<Item: ~const Destruct> + ~const Bulk,
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self {bulk} = self;
+        let mut remainder = ArrayBuffer::<_, _, true>::new();
+        let bulk = ArrayChunksWithRemainder::new_with_skip(bulk.rev(), &mut remainder, 0);
+        bulk.for_each(f);
+    }
+
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self {bulk} = self;
+        let mut remainder = ArrayBuffer::<_, _, true>::new();
+        let bulk = ArrayChunksWithRemainder::new_with_skip(bulk.rev(), &mut remainder, 0);
+        bulk.try_for_each(f)?;
+        Try::from_output(())
+    }
+}
+
+impl<I, const N: usize> Rev<RArrayChunks<I, N>>
+where
+    I: DoubleEndedBulk
+{
+    /// Calls a closure on each chunk, starting from the end of the bulk, and returns the leading elements
+    /// that did not fit into a chunk.
+    pub const fn for_each_with_remainder<F>(self, f: F) -> <ArrayBuffer<I::Item, N, true> as IntoBulk>::IntoBulk
+    where
+        I: ~const DoubleEndedBulk<Item: ~const Destruct> + ~const Bulk,
+        F: ~const FnMut([I::Item; N]) + ~const Destruct,
+        ArrayBuffer<I::Item, N, true>: ~const IntoBulk
+    {
+        let RArrayChunks { bulk } = self.into_inner();
+        let mut remainder = ArrayBuffer::new();
+        let bulk = ArrayChunksWithRemainder::new_with_skip(bulk.rev(), &mut remainder, 0);
+        bulk.for_each(f);
+        remainder.into_bulk()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+
+        let b: [_; _] = a.into_bulk()
+            .rarray_chunks::<3>()
+            .collect();
+        assert_eq!(b, [[2, 3, 4], [5, 6, 7]]);
+
+        let c: [_; _] = a.into_bulk()
+            .rarray_chunks::<3>()
+            .rev()
+            .collect();
+        assert_eq!(c, [[5, 6, 7], [2, 3, 4]]);
+
+        let mut d = vec![];
+        let r: Vec<_> = a.into_bulk()
+            .rarray_chunks::<3>()
+            .rev()
+            .for_each_with_remainder(|chunk| d.push(chunk))
+            .collect();
+        assert_eq!(d, [[5, 6, 7], [2, 3, 4]]);
+        assert_eq!(r, [1]);
+    }
+
+    #[test]
+    fn exact()
+    {
+        let digits = [1, 2, 3, 4, 5, 6, 7];
+
+        let (head, groups) = digits.into_bulk()
+            .rarray_chunks_exact::<3>();
+        let head: [_; 1] = head.collect();
+        let groups: [_; 2] = groups.collect();
+
+        assert_eq!(head, [1]);
+        assert_eq!(groups, [[2, 3, 4], [5, 6, 7]]);
+    }
+}
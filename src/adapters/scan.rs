@@ -0,0 +1,216 @@
+use core::{fmt, marker::Destruct};
+
+use array_trait::length::LengthValue;
+
+use crate::{Bulk, Map, SplitBulk, util::Scanner};
+
+/// A bulk that maps the values of `bulk` with `f`, while threading a state through every call.
+///
+/// This `struct` is created by the [`scan`](Bulk::scan) method on [`Bulk`]. See its
+/// documentation for more.
+///
+/// # Notes about splitting
+///
+/// Splitting a [`Scan`] with [`split_at`](Bulk::split_at) needs the state at the split point to start the right half.
+/// This is found by eagerly running `f` on a clone of the left half, so any side effects of `f` on the left half
+/// will happen twice.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct Scan<I, St, F>
+where
+    I: Bulk
+{
+    bulk: I,
+    state: St,
+    f: F
+}
+
+impl<I, St, F> Scan<I, St, F>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, state: St, f: F) -> Self
+    {
+        Self {
+            bulk,
+            state,
+            f
+        }
+    }
+}
+
+impl<I, St, F> fmt::Debug for Scan<I, St, F>
+where
+    I: Bulk + fmt::Debug,
+    St: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, state, f: _ } = self;
+        f.debug_struct("Scan").field("bulk", bulk).field("state", state).finish()
+    }
+}
+
+const impl<I, St, F, B> IntoIterator for Scan<I, St, F>
+where
+    I: Bulk + ~const IntoIterator<IntoIter: ~const Iterator>,
+    F: FnMut(&mut St, I::Item) -> B
+{
+    type Item = B;
+    type IntoIter = core::iter::Map<I::IntoIter, Scanner<St, F>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, state, f } = self;
+        bulk.into_iter().map(Scanner { state, f })
+    }
+}
+const impl<I, St, F, B> Bulk for Scan<I, St, F>
+where
+    I: ~const Bulk<Item: ~const Destruct>,
+    St: ~const Destruct,
+    F: ~const FnMut(&mut St, I::Item) -> B + ~const Destruct
+{
+    type MinLength = I::MinLength;
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, state: _, f: _ } = self;
+        bulk.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { bulk, state: _, f: _ } = self;
+        bulk.is_empty()
+    }
+
+    fn for_each<FF>(self, f: FF)
+    where
+        Self: Sized,
+        FF: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, state, f: scan } = self;
+        Map::new(bulk, Scanner { state, f: scan }).for_each(f)
+    }
+    fn try_for_each<FF, R>(self, f: FF) -> R
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        FF: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const core::ops::Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, state, f: scan } = self;
+        Map::new(bulk, Scanner { state, f: scan }).try_for_each(f)
+    }
+}
+const impl<I, St, F, B, L> SplitBulk<L> for Scan<I, St, F>
+where
+    I: ~const SplitBulk<L, Item: ~const Destruct, Left: ~const Bulk + ~const Clone, Right: ~const Bulk>,
+    St: ~const Clone + ~const Destruct,
+    F: ~const FnMut(&mut St, I::Item) -> B + ~const Clone + ~const Destruct,
+    B: ~const Destruct,
+    L: LengthValue
+{
+    type Left = Scan<I::Left, St, F>;
+    type Right = Scan<I::Right, St, F>;
+
+    fn split_at(Self { bulk, state, f }: Self, n: L) -> (Self::Left, Self::Right)
+    where
+        Self: Sized
+    {
+        let (left, right) = bulk.split_at(n);
+        let mut scanner = Scanner {
+            state: state.clone(),
+            f
+        };
+        left.clone().for_each(Advance(&mut scanner));
+        let Scanner { state: right_state, f } = scanner;
+        (
+            Scan::new(left, state, f.clone()),
+            Scan::new(right, right_state, f)
+        )
+    }
+}
+
+struct Advance<F>(F);
+
+const impl<F, T, U> FnOnce<(T,)> for Advance<F>
+where
+    F: ~const FnOnce(T) -> U,
+    U: ~const Destruct
+{
+    type Output = ();
+
+    extern "rust-call" fn call_once(self, (x,): (T,)) -> Self::Output
+    {
+        let Self(f) = self;
+        let _ = f(x);
+    }
+}
+const impl<F, T, U> FnMut<(T,)> for Advance<F>
+where
+    F: ~const FnMut(T) -> U,
+    U: ~const Destruct
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+    {
+        let Self(f) = self;
+        let _ = f(x);
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5];
+
+        let b: [_; _] = a.into_bulk()
+            .scan(0, |acc, x| { *acc += x; *acc })
+            .collect();
+
+        assert_eq!(b, [1, 3, 6, 10, 15]);
+    }
+
+    #[test]
+    fn split()
+    {
+        let a = [1, 2, 3, 4, 5];
+
+        let (left, right) = a.into_bulk()
+            .scan(0, |acc, x| { *acc += x; *acc })
+            .split_at([(); 2]);
+
+        let left: [_; _] = left.collect();
+        let right: [_; _] = right.collect();
+
+        assert_eq!(left, [1, 3]);
+        assert_eq!(right, [6, 10, 15]);
+    }
+
+    #[test]
+    fn in_const()
+    {
+        const fn running_max(acc: &mut u8, x: u8) -> u8
+        {
+            if x > *acc
+            {
+                *acc = x
+            }
+            *acc
+        }
+
+        let a = const {
+            [3u8, 1, 4, 1, 5, 9, 2, 6].into_bulk()
+                .scan(0, running_max)
+                .collect::<[_; _], _>()
+        };
+
+        assert_eq!(a, [3, 3, 4, 4, 5, 9, 9, 9]);
+    }
+}
@@ -0,0 +1,188 @@
+use core::{marker::Destruct, ops::Try, ptr::Pointee};
+
+use array_trait::length::{self, Length, LengthValue, Value};
+
+use crate::{Bulk, BulkLength, DoubleEndedBulk, SplitBulk};
+
+/// The index at which the bulk underlying a [`SkipLast`] is split, when the [`SkipLast`] itself is split at `M`.
+type Split<T, NN, M> = length::value::Min<M, length::value::SaturatingSub<Value<BulkLength<T>>, NN>>;
+
+/// A bulk that skips over the last `n` elements of `bulk`.
+///
+/// This `struct` is created by the [`skip_last`](Bulk::skip_last) method on [`Bulk`]. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct SkipLast<T, N = [()]>
+where
+    T: Bulk,
+    N: Length<Elem = ()> + ?Sized
+{
+    bulk: T,
+    n: <N as Pointee>::Metadata
+}
+
+impl<T, N> SkipLast<T, N>
+where
+    T: Bulk,
+    N: Length<Elem = ()> + ?Sized
+{
+    pub(crate) const fn new(bulk: T, n: N::Value) -> SkipLast<T, N>
+    {
+        Self { bulk, n: length::value::into_metadata(n) }
+    }
+}
+const impl<T, N> IntoIterator for SkipLast<T, N>
+where
+    T: ~const Bulk + ~const IntoIterator<IntoIter: ~const Iterator>,
+    N: Length<Elem = ()> + ?Sized
+{
+    type Item = T::Item;
+    type IntoIter = core::iter::Take<T::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, n } = self;
+        let m = bulk.len().saturating_sub(length::len_metadata::<N>(n));
+        bulk.into_iter()
+            .take(m)
+    }
+}
+const impl<T, N> Bulk for SkipLast<T, N>
+where
+    T: ~const Bulk<Item: ~const Destruct>,
+    N: Length<Elem = ()> + ?Sized
+{
+    type MinLength = length::SaturatingSub<T::MinLength, N>;
+    type MaxLength = length::SaturatingSub<T::MaxLength, N>;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, n } = self;
+        bulk.len().saturating_sub(length::len_metadata::<N>(*n))
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { bulk, n } = self;
+        bulk.len() <= length::len_metadata::<N>(*n)
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, n } = self;
+        let m = bulk.len().saturating_sub(length::len_metadata::<N>(n));
+        bulk.take(m).for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, n } = self;
+        let m = bulk.len().saturating_sub(length::len_metadata::<N>(n));
+        bulk.take(m).try_for_each(f)
+    }
+}
+const impl<T, N> DoubleEndedBulk for SkipLast<T, N>
+where
+    T: ~const DoubleEndedBulk<Item: ~const Destruct> + ~const Bulk,
+    N: Length<Elem = ()> + ?Sized
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, n } = self;
+        bulk.rev().skip(length::len_metadata::<N>(n)).for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, n } = self;
+        bulk.rev().skip(length::len_metadata::<N>(n)).try_for_each(f)
+    }
+}
+const impl<T, N, NN, M> SplitBulk<M> for SkipLast<T, N>
+where
+    T: ~const SplitBulk<Split<T, NN, M>, Item: ~const Destruct, Left: ~const Bulk, Right: ~const Bulk>,
+    N: Length<Elem = (), Value = NN> + ?Sized,
+    NN: LengthValue<Metadata = <N as Pointee>::Metadata, Length<()> = N>,
+    M: LengthValue
+{
+    type Left = T::Left;
+    type Right = SkipLast<T::Right, N>;
+
+    fn split_at(Self { bulk, n }: Self, m: M) -> (Self::Left, Self::Right)
+    where
+        Self: Sized
+    {
+        let n = NN::from_metadata(n);
+        let l = bulk.length();
+        let (left, right) = bulk.split_at(length::value::min(m, length::value::saturating_sub(l, n)));
+        (
+            left,
+            right.skip_last(n)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+
+        let b: [_; 4] = a.into_bulk()
+            .skip_last([(); 3])
+            .collect();
+        assert_eq!(b, [1, 2, 3, 4]);
+
+        let c: Vec<_> = a.into_bulk()
+            .skip_last(5)
+            .rev()
+            .collect();
+        assert_eq!(c, [2, 1]);
+
+        let (d, e) = a.into_bulk()
+            .skip_last([(); 3])
+            .split_at([(); 3]);
+        let d: [_; 3] = d.collect();
+        let e: [_; 1] = e.collect();
+        assert_eq!(d, [1, 2, 3]);
+        assert_eq!(e, [4]);
+    }
+    #[test]
+    fn split()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+
+        let (b, c) = a.into_bulk()
+            .skip_last(2)
+            .split_at(3);
+        let b: Vec<_> = b.collect();
+        let c: Vec<_> = c.collect();
+        assert_eq!(b, [1, 2, 3]);
+        assert_eq!(c, [4, 5]);
+
+        let (d, e) = a.as_slice()
+            .into_bulk()
+            .skip_last([(); 5])
+            .split_at(4);
+        let d: Vec<_> = d.collect();
+        let e: Vec<_> = e.collect();
+        assert_eq!(d, [&1, &2]);
+        assert!(e.is_empty());
+    }
+}
@@ -64,7 +64,7 @@ where
     N: Length<Elem = ()> + ?Sized
 {
     type MinLength = length::Min<T::MinLength, N>;
-    type MaxLength = length::Min<T::MaxLength, N>;
+    type MaxLength = <T::MaxLength as private::MaxLengthSpec<N>>::MaxLength;
 
     fn len(&self) -> usize
     {
@@ -239,6 +239,42 @@ where
     }
 }
 
+mod private
+{
+    use array_trait::length::{self, Length};
+
+    /// The most a bulk of at most `Self` elements can yield when only `N` are taken.
+    ///
+    /// Unlike [`length::Min`], this is `N` if `Self` is unbounded.
+    pub trait MaxLengthSpec<N>: Length<Elem = ()>
+    where
+        N: Length<Elem = ()> + ?Sized
+    {
+        type MaxLength: Length<Elem = ()> + ?Sized;
+    }
+    impl<L, N> MaxLengthSpec<N> for L
+    where
+        L: Length<Elem = ()> + ?Sized,
+        N: Length<Elem = ()> + ?Sized
+    {
+        default type MaxLength = length::Min<L, N>;
+    }
+    impl<N> MaxLengthSpec<N> for [()]
+    where
+        N: Length<Elem = ()> + ?Sized
+    {
+        type MaxLength = N;
+    }
+    impl<const M: usize> MaxLengthSpec<[()]> for [(); M]
+    {
+        type MaxLength = [(); M];
+    }
+    impl<const M: usize, const K: usize> MaxLengthSpec<[(); K]> for [(); M]
+    {
+        type MaxLength = length::Min<[(); M], [(); K]>;
+    }
+}
+
 #[cfg(test)]
 mod test
 {
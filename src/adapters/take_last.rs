@@ -0,0 +1,184 @@
+use core::{marker::Destruct, ops::Try, ptr::Pointee};
+
+use array_trait::length::{self, Length, LengthValue, Value};
+
+use crate::{Bulk, BulkLength, DoubleEndedBulk, SplitBulk};
+
+/// The index at which the bulk underlying a [`TakeLast`] is split, when the [`TakeLast`] itself is split at `M`.
+type Split<T, NN, M> = length::value::SaturatingAdd<length::value::SaturatingSub<Value<BulkLength<T>>, NN>, M>;
+
+/// A bulk that only delivers the last `n` iterations of `bulk`.
+///
+/// This `struct` is created by the [`take_last`](Bulk::take_last) method on [`Bulk`]. See its
+/// documentation for more.
+#[derive(Clone, Debug)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct TakeLast<T, N = [()]>
+where
+    T: Bulk,
+    N: Length<Elem = ()> + ?Sized
+{
+    bulk: T,
+    n: <N as Pointee>::Metadata
+}
+
+impl<T, N> TakeLast<T, N>
+where
+    T: Bulk,
+    N: Length<Elem = ()> + ?Sized
+{
+    pub(crate) const fn new(bulk: T, n: N::Value) -> TakeLast<T, N>
+    {
+        Self { bulk, n: length::value::into_metadata(n) }
+    }
+}
+const impl<T, N> IntoIterator for TakeLast<T, N>
+where
+    T: ~const Bulk + ~const IntoIterator<IntoIter: ~const Iterator>,
+    N: Length<Elem = ()> + ?Sized
+{
+    type Item = T::Item;
+    type IntoIter = core::iter::Skip<T::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, n } = self;
+        let m = bulk.len().saturating_sub(length::len_metadata::<N>(n));
+        bulk.into_iter()
+            .skip(m)
+    }
+}
+const impl<T, N> Bulk for TakeLast<T, N>
+where
+    T: ~const Bulk<Item: ~const Destruct>,
+    N: Length<Elem = ()> + ?Sized
+{
+    type MinLength = length::Min<T::MinLength, N>;
+    type MaxLength = length::Min<T::MaxLength, N>;
+
+    fn len(&self) -> usize
+    {
+        let Self { bulk, n } = self;
+        let n = length::len_metadata::<N>(*n);
+        Ord::min(bulk.len(), n)
+    }
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, n } = self;
+        let m = bulk.len().saturating_sub(length::len_metadata::<N>(n));
+        bulk.skip(m).for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, n } = self;
+        let m = bulk.len().saturating_sub(length::len_metadata::<N>(n));
+        bulk.skip(m).try_for_each(f)
+    }
+}
+const impl<T, N> DoubleEndedBulk for TakeLast<T, N>
+where
+    T: ~const DoubleEndedBulk<Item: ~const Destruct> + ~const Bulk,
+    N: Length<Elem = ()> + ?Sized
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { bulk, n } = self;
+        bulk.rev().take(length::len_metadata::<N>(n)).for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { bulk, n } = self;
+        bulk.rev().take(length::len_metadata::<N>(n)).try_for_each(f)
+    }
+}
+const impl<T, N, NN, M, MM> SplitBulk<M> for TakeLast<T, N>
+where
+    T: ~const SplitBulk<Split<T, NN, M>, Item: ~const Destruct, Left: ~const Bulk, Right: ~const Bulk>,
+    N: Length<Elem = (), Value = NN> + ?Sized,
+    NN: LengthValue<Metadata = <N as Pointee>::Metadata, Length<()> = N, Min<M> = MM>,
+    M: LengthValue,
+    MM: LengthValue
+{
+    type Left = TakeLast<T::Left, MM::Length<()>>;
+    type Right = T::Right;
+
+    fn split_at(Self { bulk, n }: Self, m: M) -> (Self::Left, Self::Right)
+    where
+        Self: Sized
+    {
+        let n = NN::from_metadata(n);
+        let l = bulk.length();
+        let (left, right) = bulk.split_at(length::value::saturating_add(length::value::saturating_sub(l, n), m));
+        (
+            left.take_last(length::value::min(n, m)),
+            right
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+
+        let b: [_; 3] = a.into_bulk()
+            .take_last([(); 3])
+            .collect();
+        assert_eq!(b, [5, 6, 7]);
+
+        let c: Vec<_> = a.into_bulk()
+            .take_last(10)
+            .rev()
+            .collect();
+        assert_eq!(c, [7, 6, 5, 4, 3, 2, 1]);
+
+        let (d, e) = a.into_bulk()
+            .take_last([(); 3])
+            .split_at([(); 1]);
+        let d: [_; 1] = d.collect();
+        let e: [_; 2] = e.collect();
+        assert_eq!(d, [5]);
+        assert_eq!(e, [6, 7]);
+    }
+    #[test]
+    fn split()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+
+        let (b, c) = a.into_bulk()
+            .take_last(4)
+            .split_at(1);
+        let b: Vec<_> = b.collect();
+        let c: Vec<_> = c.collect();
+        assert_eq!(b, [4]);
+        assert_eq!(c, [5, 6, 7]);
+
+        let (d, e) = a.as_slice()
+            .into_bulk()
+            .take_last([(); 2])
+            .split_at(1);
+        let d: Vec<_> = d.collect();
+        let e: Vec<_> = e.collect();
+        assert_eq!(d, [&6]);
+        assert_eq!(e, [&7]);
+    }
+}
@@ -0,0 +1,114 @@
+use core::fmt;
+
+use crate::{Bulk, util::Counted};
+
+/// A bulk that only yields elements of `bulk` while `predicate` returns `true`.
+///
+/// This `struct` is created by the [`take_while`](Bulk::take_while) method on [`Bulk`]. See its
+/// documentation for more.
+///
+/// # Notes about length
+///
+/// The length of a [`TakeWhile`] can't be known without evaluating the predicate, so iterating it only gives an upper bound
+/// as its size hint. [`len`](Bulk::len) is found by running `predicate` on a clone of the bulk, so any side effects of `predicate`
+/// will happen again every time the length is needed.
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+#[derive(Clone)]
+pub struct TakeWhile<I, P>
+where
+    I: Bulk
+{
+    bulk: I,
+    predicate: P
+}
+
+impl<I, P> TakeWhile<I, P>
+where
+    I: Bulk
+{
+    pub(crate) const fn new(bulk: I, predicate: P) -> Self
+    {
+        Self {
+            bulk,
+            predicate
+        }
+    }
+}
+
+impl<I, P> fmt::Debug for TakeWhile<I, P>
+where
+    I: Bulk + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        let Self { bulk, predicate: _ } = self;
+        f.debug_struct("TakeWhile").field("bulk", bulk).finish()
+    }
+}
+
+impl<I, P> IntoIterator for TakeWhile<I, P>
+where
+    I: Bulk,
+    P: FnMut(&I::Item) -> bool
+{
+    type Item = I::Item;
+    type IntoIter = Counted<core::iter::TakeWhile<I::IntoIter, P>>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk, predicate } = self;
+        Counted::new(bulk.into_iter().take_while(predicate))
+    }
+}
+impl<I, P> Bulk for TakeWhile<I, P>
+where
+    I: Bulk<IntoIter: Clone> + Clone,
+    P: FnMut(&I::Item) -> bool + Clone
+{
+    type MinLength = [(); 0];
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        self.clone()
+            .into_iter()
+            .count()
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().take_while(predicate).for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        let Self { bulk, predicate } = self;
+        bulk.into_iter().take_while(predicate).try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3, -1, 4];
+
+        let bulk = a.into_bulk()
+            .take_while(|&x| x > 0);
+        assert_eq!(bulk.len(), 3);
+
+        let b: Vec<_> = bulk.collect();
+        assert_eq!(b, [1, 2, 3]);
+    }
+}
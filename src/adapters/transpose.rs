@@ -0,0 +1,224 @@
+use core::{fmt, ops::{Range, Try}};
+
+use crate::{Bulk, DoubleEndedBulk, IntoBulk, StaticBulk};
+
+/// Converts the argument to a bulk and zips its elements together column-wise.
+///
+/// `iterable` is a bulk of `N` rows, each of which can be converted into a bulk of `M` elements.
+/// The resulting bulk yields `M` columns as arrays of `N` elements.
+///
+/// See the documentation of [`Bulk::transpose`](crate::Bulk::transpose) for more.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let xs = [1, 2, 3].into_bulk();
+/// let ys = [4, 5, 6].into_bulk();
+///
+/// let bulk = bulks::transpose([xs, ys]);
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [[1, 4], [2, 5], [3, 6]]);
+/// ```
+pub const fn transpose<I, const N: usize, const M: usize>(iterable: I) -> Transpose<I::IntoBulk, N, M>
+where
+    I: ~const IntoBulk<IntoBulk: StaticBulk<Array<()> = [(); N]>, Item: IntoBulk<IntoBulk: StaticBulk<Array<()> = [(); M]>>>
+{
+    Transpose::new(iterable.into_bulk())
+}
+
+/// A bulk that turns the rows of a bulk into columns.
+///
+/// This `struct` is created by [`transpose`] or [`Bulk::transpose`].
+/// See their documentation for more.
+#[derive(Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct Transpose<I, const N: usize, const M: usize>
+where
+    I: Bulk<Item: IntoBulk>
+{
+    bulk: I
+}
+
+impl<I, const N: usize, const M: usize> Transpose<I, N, M>
+where
+    I: Bulk<Item: IntoBulk>
+{
+    pub(crate) const fn new(bulk: I) -> Self
+    {
+        Self { bulk }
+    }
+}
+
+impl<I, const N: usize, const M: usize> fmt::Debug for Transpose<I, N, M>
+where
+    I: Bulk<Item: IntoBulk> + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("Transpose").field("bulk", &self.bulk).finish()
+    }
+}
+
+mod private
+{
+    use core::ops::Range;
+
+    pub struct IntoIter<R, const N: usize>
+    where
+        R: Iterator
+    {
+        pub rows: [R; N],
+        pub alive: Range<usize>
+    }
+
+    impl<R, const N: usize> Iterator for IntoIter<R, N>
+    where
+        R: Iterator
+    {
+        type Item = [R::Item; N];
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let Self { rows, alive } = self;
+            alive.next()?;
+            Some(rows.each_mut().map(|row| row.next().unwrap()))
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let len = self.len();
+            (len, Some(len))
+        }
+    }
+    impl<R, const N: usize> ExactSizeIterator for IntoIter<R, N>
+    where
+        R: Iterator
+    {
+        fn len(&self) -> usize
+        {
+            self.alive.len()
+        }
+    }
+    impl<R, const N: usize> DoubleEndedIterator for IntoIter<R, N>
+    where
+        R: DoubleEndedIterator
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            let Self { rows, alive } = self;
+            alive.next_back()?;
+            Some(rows.each_mut().map(|row| row.next_back().unwrap()))
+        }
+    }
+}
+
+type Row<I> = <<<I as IntoIterator>::Item as IntoBulk>::IntoBulk as IntoIterator>::IntoIter;
+
+impl<I, const N: usize, const M: usize> IntoIterator for Transpose<I, N, M>
+where
+    I: Bulk<Item: IntoBulk>
+{
+    type Item = [<I::Item as IntoIterator>::Item; N];
+    type IntoIter = private::IntoIter<Row<I>, N>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { bulk } = self;
+        let mut rows = bulk.into_iter()
+            .map(|row| row.into_bulk().into_iter());
+        private::IntoIter {
+            rows: core::array::from_fn(|_| rows.next().unwrap()),
+            alive: Range { start: 0, end: M }
+        }
+    }
+}
+impl<I, const N: usize, const M: usize> Bulk for Transpose<I, N, M>
+where
+    I: Bulk<Item: IntoBulk>
+{
+    type MinLength = [(); M];
+    type MaxLength = [(); M];
+
+    fn len(&self) -> usize
+    {
+        M
+    }
+    fn is_empty(&self) -> bool
+    {
+        M == 0
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        self.into_iter().try_for_each(f)
+    }
+}
+impl<I, const N: usize, const M: usize> DoubleEndedBulk for Transpose<I, N, M>
+where
+    I: Bulk<Item: IntoBulk<IntoBulk: DoubleEndedBulk>>
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().rev().for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        self.into_iter().rev().try_for_each(f)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [[1, 2, 3], [4, 5, 6]];
+
+        let b: [_; _] = a.into_bulk()
+            .transpose()
+            .collect();
+        assert_eq!(b, [[1, 4], [2, 5], [3, 6]]);
+
+        let c: [_; _] = b.into_bulk()
+            .transpose()
+            .collect();
+        assert_eq!(c, a);
+    }
+
+    #[test]
+    fn columns()
+    {
+        let f = |x| x*10;
+        let xs = [1, 2, 3].into_bulk().map(f);
+        let ys = [4, 5, 6].into_bulk().map(f);
+
+        let zipped: Vec<_> = crate::transpose([xs, ys])
+            .rev()
+            .collect();
+        assert_eq!(zipped, [[30, 60], [20, 50], [10, 40]]);
+    }
+}
@@ -0,0 +1,219 @@
+use core::marker::Destruct;
+
+use crate::{Bulk, IntoBulk, LengthMismatch, Zip};
+
+/// Converts the arguments to bulks and zips them, requiring them to be of the same length.
+///
+/// If both bulks have a length known at compile-time, mismatching lengths is a compile error.
+/// Otherwise, the lengths are compared at runtime.
+///
+/// See the documentation of [`Bulk::zip_exact`](crate::Bulk::zip_exact) for more.
+///
+/// # Panics
+///
+/// Panics if the lengths of the bulks differ.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let xs = [1, 2, 3];
+/// let ys = [4, 5, 6];
+///
+/// let bulk = bulks::zip_exact(xs, ys);
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [(1, 4), (2, 5), (3, 6)]);
+/// ```
+///
+/// Arrays of different lengths will not compile:
+///
+/// ```compile_fail
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let xs = [1, 2, 3];
+/// let ys = [4, 5];
+///
+/// let bulk = bulks::zip_exact(xs, ys);
+/// ```
+#[track_caller]
+pub const fn zip_exact<A, B>(a: A, b: B) -> Zip<A::IntoBulk, B::IntoBulk>
+where
+    A: ~const IntoBulk<IntoBulk: ~const Destruct>,
+    B: ~const IntoBulk<IntoBulk: ~const Destruct>,
+    A::IntoBulk: SameLength<B::IntoBulk>
+{
+    match try_zip_exact(a, b)
+    {
+        Ok(zip) => zip,
+        Err(err) => err.halt()
+    }
+}
+
+/// Converts the arguments to bulks and zips them, requiring them to be of the same length.
+///
+/// If both bulks have a length known at compile-time, mismatching lengths is a compile error.
+/// Otherwise, the lengths are compared at runtime.
+///
+/// See the documentation of [`Bulk::try_zip_exact`](crate::Bulk::try_zip_exact) for more.
+///
+/// # Errors
+///
+/// Returns an error if the lengths of the bulks differ.
+///
+/// # Examples
+///
+/// ```
+/// use bulks::*;
+///
+/// let xs: &[_] = &[1, 2, 3];
+/// let ys: &[_] = &[4, 5];
+///
+/// assert!(matches!(
+///     bulks::try_zip_exact(xs, ys),
+///     Err(LengthMismatch { lhs: 3, rhs: 2 })
+/// ));
+/// ```
+pub const fn try_zip_exact<A, B>(a: A, b: B) -> Result<Zip<A::IntoBulk, B::IntoBulk>, LengthMismatch>
+where
+    A: ~const IntoBulk<IntoBulk: ~const Destruct>,
+    B: ~const IntoBulk<IntoBulk: ~const Destruct>,
+    A::IntoBulk: SameLength<B::IntoBulk>
+{
+    let a = a.into_bulk();
+    let b = b.into_bulk();
+    let lhs = a.len();
+    let rhs = b.len();
+    if lhs != rhs
+    {
+        return Err(LengthMismatch { lhs, rhs })
+    }
+    Ok(Zip::new(a, b))
+}
+
+/// Bulks that may be of the same length.
+///
+/// Implemented for every pair of bulks, unless both lengths are known at compile-time and differ.
+/// Bulks with a bounded, but not exact length can only be matched with bulks of unbounded length.
+#[rustc_on_unimplemented(
+    message = "bulk `{Self}` can never be of the same length as bulk `{B}`",
+    label = "the lengths of the bulks differ",
+)]
+pub trait SameLength<B>: Bulk
+where
+    B: Bulk
+{
+
+}
+impl<A, B> SameLength<B> for A
+where
+    A: Bulk<MinLength: LengthsMatch<A::MaxLength, B::MinLength, B::MaxLength>>,
+    B: Bulk
+{
+
+}
+
+use private::LengthsMatch;
+
+mod private
+{
+    #[rustc_on_unimplemented(
+        message = "cannot zip a bulk of length `{Self}` exactly with a bulk of length `{RMin}`",
+        label = "the lengths of the bulks differ",
+    )]
+    pub trait LengthsMatch<Max, RMin, RMax>
+    where
+        Max: ?Sized,
+        RMin: ?Sized,
+        RMax: ?Sized
+    {
+
+    }
+    impl<const N: usize> LengthsMatch<[(); N], [(); N], [(); N]> for [(); N]
+    {
+
+    }
+    impl<Min, const N: usize, RMin> LengthsMatch<[(); N], RMin, [()]> for Min
+    where
+        Min: ?Sized,
+        RMin: ?Sized
+    {
+
+    }
+    impl<Min, RMin, RMax> LengthsMatch<[()], RMin, RMax> for Min
+    where
+        Min: ?Sized,
+        RMin: ?Sized,
+        RMax: ?Sized
+    {
+
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 2, 3];
+        let b: &[_] = &[4, 5, 6];
+
+        let c: Vec<_> = a.into_bulk()
+            .zip_exact(b)
+            .map(|(a, b)| a + *b)
+            .collect();
+
+        assert_eq!(c, [5, 7, 9]);
+    }
+
+    #[test]
+    fn static_arrays()
+    {
+        let a = [1, 2, 3];
+        let b = [4, 5, 6];
+
+        let c: [_; _] = a.into_bulk()
+            .zip_exact(b)
+            .collect();
+
+        assert_eq!(c, [(1, 4), (2, 5), (3, 6)]);
+    }
+
+    #[test]
+    fn bounded()
+    {
+        let a = [1, 2, 3, 4, 5, 6];
+        let b: &[_] = &[7, 8, 9];
+
+        let c: Vec<_> = b.bulk()
+            .zip_exact(a.bulk().filter(|&&x| x % 2 == 0))
+            .map(|(a, b)| a + b)
+            .collect();
+
+        assert_eq!(c, [9, 12, 15]);
+
+        let d: Vec<_> = a.bulk()
+            .filter(|&&x| x % 2 == 0)
+            .zip_exact(b)
+            .map(|(a, b)| a + b)
+            .collect();
+
+        assert_eq!(d, [9, 12, 15]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn mismatch()
+    {
+        let a = [1, 2, 3];
+        let b: &[_] = &[4, 5];
+
+        let _ = a.into_bulk().zip_exact(b);
+    }
+}
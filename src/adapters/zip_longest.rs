@@ -0,0 +1,431 @@
+use core::fmt;
+
+use array_trait::length::{self, LengthValue};
+
+use crate::{Bulk, DoubleEndedBulk, IntoBulk, SplitBulk};
+
+/// Converts the arguments to bulks and zips them, continuing until both are exhausted.
+///
+/// See the documentation of [`Bulk::zip_longest`](crate::Bulk::zip_longest) for more.
+///
+/// # Examples
+///
+/// ```
+/// # #![feature(generic_const_exprs)]
+/// use bulks::*;
+///
+/// let xs = [1, 2, 3];
+/// let ys = ['a', 'b'];
+///
+/// let bulk = bulks::zip_longest(xs, ys);
+///
+/// let s: [_; _] = bulk.collect();
+/// assert_eq!(s, [
+///     EitherOrBoth::Both(1, 'a'),
+///     EitherOrBoth::Both(2, 'b'),
+///     EitherOrBoth::Left(3)
+/// ]);
+/// ```
+pub const fn zip_longest<A, B>(a: A, b: B) -> ZipLongest<A::IntoBulk, B::IntoBulk>
+where
+    A: ~const IntoBulk,
+    B: ~const IntoBulk
+{
+    ZipLongest::new(a.into_bulk(), b.into_bulk())
+}
+
+/// A value yielded by [`ZipLongest`], telling which of the bulks still had an element.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum EitherOrBoth<A, B>
+{
+    /// Both bulks yielded an element.
+    Both(A, B),
+    /// Only the left bulk yielded an element.
+    Left(A),
+    /// Only the right bulk yielded an element.
+    Right(B)
+}
+
+impl<A, B> EitherOrBoth<A, B>
+{
+    /// Returns `true` if the left value is present.
+    pub const fn has_left(&self) -> bool
+    {
+        matches!(self, Self::Both(_, _) | Self::Left(_))
+    }
+
+    /// Returns `true` if the right value is present.
+    pub const fn has_right(&self) -> bool
+    {
+        matches!(self, Self::Both(_, _) | Self::Right(_))
+    }
+
+    /// Returns `true` if both values are present.
+    pub const fn is_both(&self) -> bool
+    {
+        matches!(self, Self::Both(_, _))
+    }
+
+    /// Converts from `&EitherOrBoth<A, B>` to `EitherOrBoth<&A, &B>`.
+    pub const fn as_ref(&self) -> EitherOrBoth<&A, &B>
+    {
+        match self
+        {
+            Self::Both(a, b) => EitherOrBoth::Both(a, b),
+            Self::Left(a) => EitherOrBoth::Left(a),
+            Self::Right(b) => EitherOrBoth::Right(b)
+        }
+    }
+
+    /// Converts from `&mut EitherOrBoth<A, B>` to `EitherOrBoth<&mut A, &mut B>`.
+    pub const fn as_mut(&mut self) -> EitherOrBoth<&mut A, &mut B>
+    {
+        match self
+        {
+            Self::Both(a, b) => EitherOrBoth::Both(a, b),
+            Self::Left(a) => EitherOrBoth::Left(a),
+            Self::Right(b) => EitherOrBoth::Right(b)
+        }
+    }
+
+    /// Returns the left value, if present.
+    pub fn left(self) -> Option<A>
+    {
+        match self
+        {
+            Self::Both(a, _) | Self::Left(a) => Some(a),
+            Self::Right(_) => None
+        }
+    }
+
+    /// Returns the right value, if present.
+    pub fn right(self) -> Option<B>
+    {
+        match self
+        {
+            Self::Both(_, b) | Self::Right(b) => Some(b),
+            Self::Left(_) => None
+        }
+    }
+
+    /// Returns both values as options.
+    pub const fn left_and_right(self) -> (Option<A>, Option<B>)
+    {
+        match self
+        {
+            Self::Both(a, b) => (Some(a), Some(b)),
+            Self::Left(a) => (Some(a), None),
+            Self::Right(b) => (None, Some(b))
+        }
+    }
+
+    /// Returns both values, replacing any missing value with the given default.
+    pub fn or(self, a: A, b: B) -> (A, B)
+    {
+        match self
+        {
+            Self::Both(a, b) => (a, b),
+            Self::Left(a) => (a, b),
+            Self::Right(b) => (a, b)
+        }
+    }
+
+    /// Returns both values, replacing any missing value with its default.
+    pub fn or_default(self) -> (A, B)
+    where
+        A: Default,
+        B: Default
+    {
+        match self
+        {
+            Self::Both(a, b) => (a, b),
+            Self::Left(a) => (a, B::default()),
+            Self::Right(b) => (A::default(), b)
+        }
+    }
+
+    /// Swaps the left and right values.
+    pub const fn flip(self) -> EitherOrBoth<B, A>
+    {
+        match self
+        {
+            Self::Both(a, b) => EitherOrBoth::Both(b, a),
+            Self::Left(a) => EitherOrBoth::Right(a),
+            Self::Right(b) => EitherOrBoth::Left(b)
+        }
+    }
+}
+
+/// A bulk that operates on two other bulks simultaneously, until both of them are exhausted.
+///
+/// This `struct` is created by [`zip_longest`] or [`Bulk::zip_longest`].
+/// See their documentation for more.
+#[derive(Clone)]
+#[must_use = "bulks are lazy and do nothing unless consumed"]
+pub struct ZipLongest<A, B>
+where
+    A: Bulk,
+    B: Bulk
+{
+    a: A,
+    b: B
+}
+
+impl<A, B> ZipLongest<A, B>
+where
+    A: Bulk,
+    B: Bulk
+{
+    pub(crate) const fn new(a: A, b: B) -> Self
+    {
+        Self { a, b }
+    }
+}
+
+impl<A, B> fmt::Debug for ZipLongest<A, B>
+where
+    A: Bulk + fmt::Debug,
+    B: Bulk + fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_struct("ZipLongest").field("a", &self.a).field("b", &self.b).finish()
+    }
+}
+
+const fn zip_once<A, B>(a: Option<A>, b: Option<B>) -> Option<EitherOrBoth<A, B>>
+{
+    match (a, b)
+    {
+        (Some(a), Some(b)) => Some(EitherOrBoth::Both(a, b)),
+        (Some(a), None) => Some(EitherOrBoth::Left(a)),
+        (None, Some(b)) => Some(EitherOrBoth::Right(b)),
+        (None, None) => None
+    }
+}
+
+mod private
+{
+    use crate::{EitherOrBoth, adapters::zip_longest::zip_once};
+
+    pub struct IntoIter<A, B>
+    where
+        A: Iterator,
+        B: Iterator
+    {
+        pub a: A,
+        pub b: B
+    }
+
+    const impl<A, B> Iterator for IntoIter<A, B>
+    where
+        A: ~const Iterator,
+        B: ~const Iterator
+    {
+        type Item = EitherOrBoth<A::Item, B::Item>;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let Self { a, b } = self;
+            zip_once(a.next(), b.next())
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let Self { a, b } = self;
+            let (a_min, a_max) = a.size_hint();
+            let (b_min, b_max) = b.size_hint();
+            (
+                a_min.max(b_min),
+                match (a_max, b_max)
+                {
+                    (Some(a_max), Some(b_max)) => Some(a_max.max(b_max)),
+                    _ => None
+                }
+            )
+        }
+    }
+    /*const*/ impl<A, B> ExactSizeIterator for IntoIter<A, B>
+    where
+        A: ExactSizeIterator,
+        B: ExactSizeIterator
+    {
+        fn is_empty(&self) -> bool
+        {
+            let Self { a, b } = self;
+            a.is_empty() && b.is_empty()
+        }
+        fn len(&self) -> usize
+        {
+            let Self { a, b } = self;
+            a.len().max(b.len())
+        }
+    }
+    /*const*/ impl<A, B> DoubleEndedIterator for IntoIter<A, B>
+    where
+        A: DoubleEndedIterator + ExactSizeIterator,
+        B: DoubleEndedIterator + ExactSizeIterator
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            let Self { a, b } = self;
+            let a_len = a.len();
+            let b_len = b.len();
+            if a_len > b_len
+            {
+                return a.next_back().map(EitherOrBoth::Left)
+            }
+            if b_len > a_len
+            {
+                return b.next_back().map(EitherOrBoth::Right)
+            }
+            zip_once(a.next_back(), b.next_back())
+        }
+    }
+}
+
+const impl<A, B> IntoIterator for ZipLongest<A, B>
+where
+    A: Bulk + ~const IntoIterator,
+    B: Bulk + ~const IntoIterator
+{
+    type Item = EitherOrBoth<A::Item, B::Item>;
+    type IntoIter = private::IntoIter<A::IntoIter, B::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { a, b } = self;
+        private::IntoIter {
+            a: a.into_iter(),
+            b: b.into_iter()
+        }
+    }
+}
+impl<A, B> Bulk for ZipLongest<A, B>
+where
+    A: Bulk,
+    B: Bulk
+{
+    type MinLength = length::Max<A::MinLength, B::MinLength>;
+    type MaxLength = length::Max<A::MaxLength, B::MaxLength>;
+
+    fn len(&self) -> usize
+    {
+        let Self { a, b } = self;
+        Ord::max(a.len(), b.len())
+    }
+    fn is_empty(&self) -> bool
+    {
+        let Self { a, b } = self;
+        a.is_empty() && b.is_empty()
+    }
+
+    fn first(self) -> Option<Self::Item>
+    where
+        Self: Sized
+    {
+        let Self { a, b } = self;
+        zip_once(a.first(), b.first())
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().for_each(f)
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        self.into_iter().try_for_each(f)
+    }
+}
+impl<A, B> DoubleEndedBulk for ZipLongest<A, B>
+where
+    A: DoubleEndedBulk,
+    B: DoubleEndedBulk
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().rev().for_each(f)
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: core::ops::Try<Output = ()>
+    {
+        self.into_iter().rev().try_for_each(f)
+    }
+}
+const impl<A, B, L> SplitBulk<L> for ZipLongest<A, B>
+where
+    A: ~const SplitBulk<L, Left: ~const Bulk, Right: ~const Bulk>,
+    B: ~const SplitBulk<L, Left: ~const Bulk, Right: ~const Bulk>,
+    Self: ~const Bulk,
+    ZipLongest<A::Left, B::Left>: ~const Bulk<Item = Self::Item>,
+    ZipLongest<A::Right, B::Right>: ~const Bulk<Item = Self::Item>,
+    L: LengthValue
+{
+    type Left = ZipLongest<A::Left, B::Left>;
+    type Right = ZipLongest<A::Right, B::Right>;
+
+    fn split_at(Self { a, b }: Self, n: L) -> (Self::Left, Self::Right)
+    where
+        Self: Sized
+    {
+        let (a_left, a_right) = a.split_at(n);
+        let (b_left, b_right) = b.split_at(n);
+
+        (
+            ZipLongest::new(a_left, b_left),
+            ZipLongest::new(a_right, b_right)
+        )
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [1, 3, 5];
+        let b = [2, 4, 6, 7];
+        let bulk = a.into_bulk()
+            .zip_longest(b);
+        let c = bulk.collect::<[_; _], _>();
+
+        assert_eq!(c, [
+            EitherOrBoth::Both(1, 2),
+            EitherOrBoth::Both(3, 4),
+            EitherOrBoth::Both(5, 6),
+            EitherOrBoth::Right(7)
+        ])
+    }
+
+    #[test]
+    fn rev()
+    {
+        let a = [1, 2, 3];
+        let b = ['a'];
+        let c: [_; _] = a.into_bulk()
+            .zip_longest(b)
+            .rev()
+            .collect();
+
+        assert_eq!(c, [
+            EitherOrBoth::Left(3),
+            EitherOrBoth::Left(2),
+            EitherOrBoth::Both(1, 'a')
+        ])
+    }
+}
@@ -0,0 +1,443 @@
+use core::{borrow::{Borrow, BorrowMut}, fmt, hash::{Hash, Hasher}, marker::Destruct, mem::MaybeUninit, ops::{Deref, DerefMut, Index, IndexMut}, ptr, slice::SliceIndex};
+
+use array_trait::AsSlice;
+
+/// An array with a fixed capacity `N`, holding anywhere from `0` to `N` elements.
+///
+/// This is what bulks with a bounded, but not exact length can be collected into without allocating.
+/// See [`Bulk::collect_nearest`](crate::Bulk::collect_nearest).
+///
+/// # Examples
+///
+/// ```
+/// use bulks::*;
+///
+/// let a = [1, 2, 3, 4, 5, 6];
+///
+/// let even: BoundedArray<_, 6> = a.into_bulk()
+///     .filter(|x| x % 2 == 0)
+///     .collect();
+///
+/// assert_eq!(even.len(), 3);
+/// assert_eq!(even.as_slice(), [2, 4, 6]);
+/// ```
+pub struct BoundedArray<T, const N: usize>
+{
+    array: [MaybeUninit<T>; N],
+    len: usize
+}
+
+impl<T, const N: usize> BoundedArray<T, N>
+{
+    /// Creates an empty array with capacity `N`.
+    pub const fn new() -> Self
+    {
+        Self {
+            array: [const { MaybeUninit::uninit() }; N],
+            len: 0
+        }
+    }
+
+    /// Returns the number of elements in the array.
+    pub const fn len(&self) -> usize
+    {
+        self.len
+    }
+
+    /// Returns `true` if the array holds no elements.
+    pub const fn is_empty(&self) -> bool
+    {
+        self.len == 0
+    }
+
+    /// Returns `true` if the array can't hold any more elements.
+    pub const fn is_full(&self) -> bool
+    {
+        self.len == N
+    }
+
+    /// Returns the maximum number of elements the array can hold.
+    pub const fn capacity(&self) -> usize
+    {
+        N
+    }
+
+    /// Returns the initialized elements as a slice.
+    pub const fn as_slice(&self) -> &[T]
+    {
+        unsafe {
+            core::slice::from_raw_parts(self.array.as_ptr().cast(), self.len)
+        }
+    }
+
+    /// Returns the initialized elements as a mutable slice.
+    pub const fn as_mut_slice(&mut self) -> &mut [T]
+    {
+        unsafe {
+            core::slice::from_raw_parts_mut(self.array.as_mut_ptr().cast(), self.len)
+        }
+    }
+
+    /// Appends an element to the back of the array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the array is full.
+    #[track_caller]
+    pub const fn push(&mut self, value: T)
+    where
+        T: ~const Destruct
+    {
+        if self.try_push(value).is_err()
+        {
+            panic!("Bounded array is full.")
+        }
+    }
+
+    /// Appends an element to the back of the array, or gives it back if the array is full.
+    pub const fn try_push(&mut self, value: T) -> Result<(), T>
+    {
+        if self.is_full()
+        {
+            return Err(value)
+        }
+        self.array[self.len].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Removes the last element and returns it, or [`None`] if the array is empty.
+    pub const fn pop(&mut self) -> Option<T>
+    {
+        if self.is_empty()
+        {
+            return None
+        }
+        self.len -= 1;
+        Some(unsafe {
+            self.array[self.len].assume_init_read()
+        })
+    }
+
+    /// Shortens the array to `len` elements, dropping the rest.
+    ///
+    /// Does nothing if the array already holds `len` or fewer elements.
+    pub fn truncate(&mut self, len: usize)
+    {
+        if len >= self.len
+        {
+            return
+        }
+        let tail = ptr::slice_from_raw_parts_mut(self.array[len..].as_mut_ptr().cast::<T>(), self.len - len);
+        self.len = len;
+        unsafe {
+            ptr::drop_in_place(tail)
+        }
+    }
+
+    /// Removes all elements from the array.
+    pub fn clear(&mut self)
+    {
+        self.truncate(0)
+    }
+
+    /// Splits the array in two at the given index, moving the elements from `at` and onward into a new array.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `at > len`.
+    #[track_caller]
+    pub const fn split_off(&mut self, at: usize) -> Self
+    {
+        assert!(at <= self.len, "Index out of bounds.");
+
+        let mut right = Self::new();
+        let n = self.len - at;
+        unsafe {
+            ptr::copy_nonoverlapping(self.array.as_ptr().add(at), right.array.as_mut_ptr(), n);
+        }
+        right.len = n;
+        self.len = at;
+        right
+    }
+
+    /// Converts the array into a full array, or gives it back if it isn't full.
+    pub const fn into_array(self) -> Result<[T; N], Self>
+    {
+        if !self.is_full()
+        {
+            return Err(self)
+        }
+        let this = core::mem::ManuallyDrop::new(self);
+        Ok(unsafe {
+            ptr::read(&this.array).transpose().assume_init()
+        })
+    }
+
+    pub(crate) const fn into_raw_parts(self) -> ([MaybeUninit<T>; N], usize)
+    {
+        let this = core::mem::ManuallyDrop::new(self);
+        (unsafe { ptr::read(&this.array) }, this.len)
+    }
+}
+
+impl<T, const N: usize> Drop for BoundedArray<T, N>
+{
+    fn drop(&mut self)
+    {
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice())
+        }
+    }
+}
+
+impl<T, const N: usize> Default for BoundedArray<T, N>
+{
+    fn default() -> Self
+    {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Clone for BoundedArray<T, N>
+where
+    T: Clone
+{
+    fn clone(&self) -> Self
+    {
+        let mut clone = Self::new();
+        for x in self.as_slice()
+        {
+            clone.push(x.clone());
+        }
+        clone
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for BoundedArray<T, N>
+where
+    T: fmt::Debug
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result
+    {
+        f.debug_list().entries(self.as_slice()).finish()
+    }
+}
+
+impl<T, U, const N: usize, const M: usize> PartialEq<BoundedArray<U, M>> for BoundedArray<T, N>
+where
+    T: PartialEq<U>
+{
+    fn eq(&self, other: &BoundedArray<U, M>) -> bool
+    {
+        self.as_slice() == other.as_slice()
+    }
+}
+impl<T, const N: usize> Eq for BoundedArray<T, N>
+where
+    T: Eq
+{
+
+}
+impl<T, const N: usize> Hash for BoundedArray<T, N>
+where
+    T: Hash
+{
+    fn hash<H: Hasher>(&self, state: &mut H)
+    {
+        self.as_slice().hash(state)
+    }
+}
+
+impl<T, const N: usize> Deref for BoundedArray<T, N>
+{
+    type Target = [T];
+
+    fn deref(&self) -> &Self::Target
+    {
+        self.as_slice()
+    }
+}
+impl<T, const N: usize> DerefMut for BoundedArray<T, N>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target
+    {
+        self.as_mut_slice()
+    }
+}
+impl<T, const N: usize> AsSlice for BoundedArray<T, N>
+{
+    type Elem = T;
+
+    fn as_slice(&self) -> &[T]
+    {
+        BoundedArray::as_slice(self)
+    }
+
+    fn as_mut_slice(&mut self) -> &mut [T]
+    {
+        BoundedArray::as_mut_slice(self)
+    }
+}
+
+impl<T, const N: usize> AsRef<[T]> for BoundedArray<T, N>
+{
+    fn as_ref(&self) -> &[T]
+    {
+        self.as_slice()
+    }
+}
+impl<T, const N: usize> AsMut<[T]> for BoundedArray<T, N>
+{
+    fn as_mut(&mut self) -> &mut [T]
+    {
+        self.as_mut_slice()
+    }
+}
+impl<T, const N: usize> Borrow<[T]> for BoundedArray<T, N>
+{
+    fn borrow(&self) -> &[T]
+    {
+        self.as_slice()
+    }
+}
+impl<T, const N: usize> BorrowMut<[T]> for BoundedArray<T, N>
+{
+    fn borrow_mut(&mut self) -> &mut [T]
+    {
+        self.as_mut_slice()
+    }
+}
+impl<T, I, const N: usize> Index<I> for BoundedArray<T, N>
+where
+    I: SliceIndex<[T]>
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output
+    {
+        self.as_slice().index(index)
+    }
+}
+impl<T, I, const N: usize> IndexMut<I> for BoundedArray<T, N>
+where
+    I: SliceIndex<[T]>
+{
+    fn index_mut(&mut self, index: I) -> &mut Self::Output
+    {
+        self.as_mut_slice().index_mut(index)
+    }
+}
+
+impl<T, const N: usize> From<[T; N]> for BoundedArray<T, N>
+{
+    fn from(array: [T; N]) -> Self
+    {
+        Self {
+            array: MaybeUninit::new(array).transpose(),
+            len: N
+        }
+    }
+}
+
+/// Collects into the array.
+///
+/// # Panics
+///
+/// Panics if there are more than `N` elements.
+impl<T, const N: usize> FromIterator<T> for BoundedArray<T, N>
+{
+    #[track_caller]
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self
+    {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+/// Extends the array.
+///
+/// # Panics
+///
+/// Panics if the array overflows.
+impl<T, const N: usize> Extend<T> for BoundedArray<T, N>
+{
+    #[track_caller]
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I)
+    {
+        for x in iter
+        {
+            self.push(x)
+        }
+    }
+}
+
+impl<'a, T, const N: usize> IntoIterator for &'a BoundedArray<T, N>
+{
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.as_slice().iter()
+    }
+}
+impl<'a, T, const N: usize> IntoIterator for &'a mut BoundedArray<T, N>
+{
+    type Item = &'a mut T;
+    type IntoIter = core::slice::IterMut<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.as_mut_slice().iter_mut()
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let mut a = BoundedArray::<_, 4>::new();
+        a.push(1);
+        a.push(2);
+        a.push(3);
+        assert_eq!(a.try_push(4), Ok(()));
+        assert_eq!(a.try_push(5), Err(5));
+        assert_eq!(a.pop(), Some(4));
+
+        let b = a.split_off(1);
+        assert_eq!(a.as_slice(), [1]);
+        assert_eq!(b.as_slice(), [2, 3]);
+
+        let c: Vec<_> = b.into_iter().rev().collect();
+        assert_eq!(c, [3, 2]);
+    }
+
+    #[test]
+    fn drops()
+    {
+        use std::rc::Rc;
+
+        let x = Rc::new(());
+        let mut a = BoundedArray::<_, 3>::new();
+        a.push(x.clone());
+        a.push(x.clone());
+
+        let mut iter = a.into_iter();
+        let _ = iter.next();
+        assert_eq!(Rc::strong_count(&x), 2);
+
+        core::mem::drop(iter);
+        assert_eq!(Rc::strong_count(&x), 1);
+
+        let mut b = BoundedArray::<_, 1>::new();
+        b.push(x.clone());
+        let overflow = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| b.push(x.clone())));
+        assert!(overflow.is_err());
+        assert_eq!(Rc::strong_count(&x), 2);
+    }
+}
@@ -1,11 +1,83 @@
-use core::{borrow::BorrowMut, cmp::Ordering, fmt::Display, iter::Step, marker::Destruct, ops::{Add, ControlFlow, FromResidual, Mul, Residual, Try}};
+use core::{borrow::BorrowMut, cmp::Ordering, fmt::Display, iter::Step, marker::{Destruct, PhantomData}, ops::{Add, ControlFlow, FromResidual, Mul, Residual, Try}};
 
 use array_trait::{length::{self, Length, LengthValue, Value}};
 
-use crate::{ArrayChunks, Chain, Cloned, CollectionAdapter, CollectionStrategy, Copied, DoubleEndedBulk, Enumerate, EnumerateFrom, FlatMap, Flatten, FromBulk, Inspect, Intersperse, IntersperseWith, IntoBulk, IntoContained, IntoContainedBy, Map, MapWindows, Merge, Mutate, Nearest, Resize, ResizeWith, Rev, Skip, SplitBulk, StaticBulk, StepBy, Take, TryCollectionStrategy, Zip, util};
+use crate::{Accumulate, AccumulateExclusive, ArrayChunks, CartesianProduct, Chain, Cloned, CollectionAdapter, CollectionStrategy, CollectNearest, Copied, CycleN, DivisibleLength, DoubleEndedBulk, Enumerate, EnumerateFrom, Filter, FilterMap, FlatMap, Flatten, FromBulk, Inspect, Intersperse, IntersperseWith, Interleave, IntoBulk, IntoContained, IntoContainedBy, IntoCursor, Map, MapWhile, MapWindows, MapWindowsStep, Merge, Mutate, Nearest, NearestLength, Once, RArrayChunks, Resize, ResizeWith, Rev, SameLength, Scan, Skip, SkipLast, SkipWhile, SplitBulk, SplitIntoBulk, SplitIntoLength, StaticBulk, StepBy, Take, TakeLast, TakeWhile, Transpose, TryCollectionStrategy, UnzipBuffer, UnzipBulk, Zip, ZipLongest, range::Range, util};
 
 pub type BulkLength<B> = <<B as Bulk>::MinLength as Length>::Intersect<<B as Bulk>::MaxLength>;
 
+/// The index at which [`Bulk::array_chunks_exact`] splits a bulk, which is its length rounded down to a multiple of `N`.
+pub type ChunksExactSplit<B, const N: usize> = length::value::SaturatingSub<Value<BulkLength<B>>, length::value::Rem<Value<BulkLength<B>>, [(); N]>>;
+
+/// The first or last element of a bulk, as returned by [`Bulk::split_first`] and [`Bulk::split_last`].
+///
+/// If the bulk is known to be non-empty at compile-time, this is the element itself. Otherwise it's an [`Option`].
+pub type Head<B> = <length::Min<<B as Bulk>::MinLength, [(); 1]> as HeadLength>::Head<<B as IntoIterator>::Item>;
+
+pub const trait HeadLength: Length<Elem = ()>
+{
+    type Head<T>;
+
+    fn head<T>(head: Option<T>) -> Self::Head<T>;
+}
+const impl HeadLength for [()]
+{
+    type Head<T> = Option<T>;
+
+    fn head<T>(head: Option<T>) -> Self::Head<T>
+    {
+        head
+    }
+}
+const impl HeadLength for [(); 0]
+{
+    type Head<T> = Option<T>;
+
+    fn head<T>(head: Option<T>) -> Self::Head<T>
+    {
+        head
+    }
+}
+const impl HeadLength for [(); 1]
+{
+    type Head<T> = T;
+
+    #[track_caller]
+    fn head<T>(head: Option<T>) -> Self::Head<T>
+    {
+        head.unwrap()
+    }
+}
+
+/// A length that an index `I` can be within.
+///
+/// If both the length and the index are known at compile-time, this is only implemented if the index is in range,
+/// so an out-of-range index fails to compile. Otherwise, the index is checked at runtime.
+pub trait InBounds<I>: Length<Elem = ()>
+where
+    I: LengthValue
+{
+
+}
+impl InBounds<usize> for [()]
+{
+
+}
+impl<const M: usize> InBounds<[(); M]> for [()]
+{
+
+}
+impl<const N: usize> InBounds<usize> for [(); N]
+{
+
+}
+impl<const N: usize, const M: usize> InBounds<[(); M]> for [(); N]
+where
+    [(); N - M]:
+{
+
+}
+
 //fn _assert_is_dyn_compatible(_: &dyn Bulk<Item = ()>) {}
 
 /// A trait for dealing with bulks.
@@ -1083,609 +1155,678 @@ pub const trait Bulk: ~const IntoBulk<IntoBulk = Self>
 
         self.reduce(Functor { compare })
     }
-    
-    /// Creates a bulk starting at the same point, but stepping by
-    /// the given amount at each iteration.
-    /// 
-    /// Similar to [`Iterator::step_by`].
-    ///
-    /// # Panics
+
+    /// Determines if the elements of this bulk are equal to those of another.
     ///
-    /// The method will panic if the given step is `0`.
+    /// The lengths of the bulks are compared first, so bulks of different lengths are never equal, and no elements are visited.
+    /// If both bulks have a length known at compile-time, this is resolved at compile-time.
     ///
     /// # Examples
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a = [0, 1, 2, 3, 4, 5];
-    /// 
-    /// let mut bulk = a.into_bulk().step_by([(); 2]);
-    /// let a_even: [_; _] = bulk.collect();
     ///
-    /// assert_eq!(a_even, [0, 2, 4]);
-    /// 
-    /// let mut bulk = a.into_bulk().skip([(); 1]).step_by([(); 2]);
-    /// let a_odd: [_; _] = bulk.collect();
-    /// 
-    /// assert_eq!(a_odd, [1, 3, 5]);
+    /// assert!([1, 2, 3].into_bulk().eq([1, 2, 3]));
+    /// assert!(![1, 2, 3].into_bulk().eq([1, 2, 4]));
+    /// assert!(![1, 2, 3].into_bulk().eq([1, 2]));
     /// ```
-    #[inline]
-    #[track_caller]
-    fn step_by<L>(self, step: L) -> StepBy<Self, L::Length<()>>
+    fn eq<I>(self, other: I) -> bool
     where
-        Self: Sized,
-        L: LengthValue
+        Self: Sized + ~const Destruct,
+        Self::Item: ~const PartialEq<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct> + ~const Destruct>
     {
-        StepBy::new(self, step)
+        const fn eq<T, U>(x: T, y: U) -> bool
+        where
+            T: ~const PartialEq<U> + ~const Destruct,
+            U: ~const Destruct
+        {
+            x == y
+        }
+
+        self.eq_by(other, eq)
     }
 
-    /// Takes two bulks and creates a new bulk over both in sequence.
+    /// Determines if the elements of this bulk are equal to those of another with respect to the specified equality function.
     ///
-    /// In other words, it links two bulks together, in a chain. 🔗
-    /// 
-    /// Similar to [`Iterator::chain`].
+    /// See [`Bulk::eq`] for more.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let s1 = b"abc";
-    /// let s2 = b"def";
     ///
-    /// let mut bulk = s1.into_bulk()
-    ///     .chain(s2)
-    ///     .copied();
-    /// 
-    /// let s: [_; _] = bulk.collect();
-    /// 
-    /// assert_eq!(s, *b"abcdef");
+    /// let xs = [1, 2, 3, 4];
+    /// let ys = [1, 4, 9, 16];
+    ///
+    /// assert!(xs.into_bulk().eq_by(ys, |x, y| x * x == y));
     /// ```
+    fn eq_by<I, F>(self, other: I, eq: F) -> bool
+    where
+        Self: Sized + ~const Destruct,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct> + ~const Destruct>,
+        F: ~const FnMut(Self::Item, I::Item) -> bool + ~const Destruct
+    {
+        let other = other.into_bulk();
+        if length::value::ne(self.length(), other.length())
+        {
+            return false
+        }
+        compare_by(self, other, eq, true).is_none()
+    }
+
+    /// Determines if the elements of this bulk are not equal to those of another.
     ///
-    /// Since the argument to [`chain()`](Bulk::chain) uses [`IntoBulk`], we can pass
-    /// anything that can be converted into a [`Bulk`], not just a
-    /// [`Bulk`] itself. For example, arrays (`[T; _]`) implement
-    /// [`IntoBulk`], and so can be passed to [`chain()`](Bulk::chain) directly:
+    /// See [`Bulk::eq`] for more.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a1 = [1, 2, 3];
-    /// let a2 = [4, 5, 6];
     ///
-    /// let mut bulk = a1.into_bulk()
-    ///     .chain(a2);
-    /// 
-    /// let a: [_; _] = bulk.collect();
-    /// 
-    /// assert_eq!(a, [1, 2, 3, 4, 5, 6]);
+    /// assert!(![1].into_bulk().ne([1]));
+    /// assert!([1].into_bulk().ne([1, 1]));
     /// ```
-    #[inline]
-    #[track_caller]
-    fn chain<U>(self, other: U) -> Chain<Self, U::IntoBulk>
+    fn ne<I>(self, other: I) -> bool
     where
-        Self: Sized,
-        U: ~const IntoBulk<Item = Self::Item>,
+        Self: Sized + ~const Destruct,
+        Self::Item: ~const PartialEq<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct> + ~const Destruct>
     {
-        Chain::new(self, other.into_bulk())
+        !self.eq(other)
     }
 
-    /// 'Zips up' two bulks or iterators into a single bulk of pairs. One of them must be a bulk.
-    /// 
-    /// Similar to [`Iterator::zip`].
+    /// Determines if the elements of this bulk are not equal to those of another with respect to the specified equality function.
     ///
-    /// # Examples
+    /// See [`Bulk::eq`] for more.
+    fn ne_by<I, F>(self, other: I, eq: F) -> bool
+    where
+        Self: Sized + ~const Destruct,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct> + ~const Destruct>,
+        F: ~const FnMut(Self::Item, I::Item) -> bool + ~const Destruct
+    {
+        !self.eq_by(other, eq)
+    }
+
+    /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this bulk with those
+    /// of another.
     ///
-    /// Basic usage:
+    /// # Examples
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let s1 = b"abc".into_bulk().copied();
-    /// let s2 = b"def".into_bulk().copied();
+    /// use core::cmp::Ordering;
     ///
-    /// let mut bulk = s1.zip(s2);
-    /// 
-    /// let s: [_; _] = bulk.collect();
-    /// 
-    /// assert_eq!(s, [(b'a', b'd'), (b'b', b'e'), (b'c', b'f')]);
+    /// assert_eq!([1].into_bulk().cmp([1]), Ordering::Equal);
+    /// assert_eq!([1].into_bulk().cmp([1, 2]), Ordering::Less);
+    /// assert_eq!([1, 2].into_bulk().cmp([1]), Ordering::Greater);
     /// ```
+    fn cmp<I>(self, other: I) -> Ordering
+    where
+        Self: Sized,
+        Self::Item: ~const Ord + ~const Destruct,
+        I: ~const IntoBulk<Item = <Self as IntoIterator>::Item, IntoBulk: ~const IntoCursor>
+    {
+        const fn cmp<T>(x: T, y: T) -> Ordering
+        where
+            T: ~const Ord + ~const Destruct
+        {
+            x.cmp(&y)
+        }
+
+        self.cmp_by(other, cmp)
+    }
+
+    /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this bulk with those
+    /// of another with respect to the specified comparison function.
     ///
-    /// Since the argument to [`zip()`](Bulk::zip) uses [`IntoBulk`], we can pass
-    /// anything that can be converted into a [`Bulk`], not just a
-    /// [`Bulk`] itself. For example, arrays (`[T]`) implement
-    /// [`IntoBulk`], and so can be passed to [`zip()`](Bulk::zip) directly:
+    /// # Examples
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a1 = [1, 2, 3];
-    /// let a2 = [4, 5, 6];
+    /// use core::cmp::Ordering;
     ///
-    /// let mut bulk = a1.into_bulk().zip(a2);
+    /// let xs = [1, 2, 3, 4];
+    /// let ys = [1, 4, 9, 16];
     ///
-    /// let a: [_; _] = bulk.collect();
-    /// 
-    /// assert_eq!(a, [(1, 4), (2, 5), (3, 6)]);
+    /// assert_eq!(xs.into_bulk().cmp_by(ys, |x, y| x.cmp(&y)), Ordering::Less);
+    /// assert_eq!(xs.into_bulk().cmp_by(ys, |x, y| (x * x).cmp(&y)), Ordering::Equal);
+    /// assert_eq!(xs.into_bulk().cmp_by(ys, |x, y| (2 * x).cmp(&y)), Ordering::Greater);
     /// ```
+    fn cmp_by<I, F>(self, other: I, cmp: F) -> Ordering
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>,
+        F: ~const FnMut(Self::Item, I::Item) -> Ordering + ~const Destruct
+    {
+        let other = other.into_bulk();
+        let by_length = length::value::cmp(self.length(), other.length());
+        match compare_by(self, other, cmp, Ordering::Equal)
+        {
+            Some(ordering) => ordering,
+            None => by_length
+        }
+    }
+
+    /// [Lexicographically](Ord#lexicographical-comparison) compares the [`PartialOrd`] elements of
+    /// this bulk with those of another. The comparison works like short-circuit
+    /// evaluation, returning a result without comparing the remaining elements.
+    /// As soon as an order can be determined, the evaluation stops and a result is returned.
     ///
-    /// `zip()` is often used to zip an infinite iterator to a finite one.
-    /// This works because the finite iterator will eventually return [`None`],
-    /// ending the zipper. Zipping with `(0..)` can look a lot like [`enumerate`]:
+    /// # Examples
     ///
     /// ```
     /// use bulks::*;
-    /// 
-    /// let enumerate: [_; _] = (*b"foo").into_bulk().enumerate().collect();
+    /// use core::cmp::Ordering;
     ///
-    /// let zipper: Vec<_> = bulks::rzip(0.., *b"foo").collect();
-    /// 
-    /// assert_eq!((0, b'f'), enumerate[0]);
-    /// assert_eq!((0, b'f'), zipper[0]);
-    /// 
-    /// assert_eq!((1, b'o'), enumerate[1]);
-    /// assert_eq!((1, b'o'), zipper[1]);
-    /// 
-    /// assert_eq!((2, b'o'), enumerate[2]);
-    /// assert_eq!((2, b'o'), zipper[2]);
+    /// assert_eq!([1.].into_bulk().partial_cmp([1.]), Some(Ordering::Equal));
+    /// assert_eq!([1.].into_bulk().partial_cmp([1., 2.]), Some(Ordering::Less));
+    /// assert_eq!([1., 2.].into_bulk().partial_cmp([1.]), Some(Ordering::Greater));
+    ///
+    /// assert_eq!([f64::NAN].into_bulk().partial_cmp([1.]), None);
     /// ```
+    fn partial_cmp<I>(self, other: I) -> Option<Ordering>
+    where
+        Self: Sized,
+        Self::Item: ~const PartialOrd<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>
+    {
+        const fn partial_cmp<T, U>(x: T, y: U) -> Option<Ordering>
+        where
+            T: ~const PartialOrd<U> + ~const Destruct,
+            U: ~const Destruct
+        {
+            x.partial_cmp(&y)
+        }
+
+        self.partial_cmp_by(other, partial_cmp)
+    }
+
+    /// [Lexicographically](Ord#lexicographical-comparison) compares the elements of this bulk with those
+    /// of another with respect to the specified comparison function.
     ///
-    /// It can be more readable to use [`bulks::zip`](crate::zip):
+    /// # Examples
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
+    /// use core::cmp::Ordering;
     ///
-    /// let a = [1, 2, 3];
-    /// let b = [2, 3, 4];
+    /// let xs = [1.0, 2.0, 3.0, 4.0];
+    /// let ys = [1.0, 4.0, 9.0, 16.0];
     ///
-    /// let mut zipped = bulks::zip(
-    ///     a.into_bulk().map(|x| x * 2).skip([(); 1]),
-    ///     b.into_bulk().map(|x| x * 2).skip([(); 1]),
+    /// assert_eq!(
+    ///     xs.into_bulk().partial_cmp_by(ys, |x, y| x.partial_cmp(&y)),
+    ///     Some(Ordering::Less)
+    /// );
+    /// assert_eq!(
+    ///     xs.into_bulk().partial_cmp_by(ys, |x, y| (x * x).partial_cmp(&y)),
+    ///     Some(Ordering::Equal)
     /// );
-    /// 
-    /// let c: [_; _] = zipped.collect();
-    /// 
-    /// assert_eq!(c, [(4, 6), (6, 8)]);
-    /// ```
-    ///
-    /// compared to:
-    ///
-    /// ```
-    /// # #![feature(generic_const_exprs)]
-    /// # use bulks::*;
-    /// #
-    /// # let a = [1, 2, 3];
-    /// # let b = [2, 3, 4];
-    /// #
-    /// let mut zipped = a.into_bulk()
-    ///     .map(|x| x * 2)
-    ///     .skip([(); 1])
-    ///     .zip(b.into_bulk()
-    ///         .map(|x| x * 2)
-    ///         .skip([(); 1])
-    ///     );
-    /// #
-    /// # let c: [_; _] = zipped.collect();
-    /// # assert_eq!(c, [(4, 6), (6, 8)]);
     /// ```
-    #[inline]
-    #[track_caller]
-    fn zip<U>(self, other: U) -> Zip<Self, <<U as IntoContained>::IntoContained as IntoBulk>::IntoBulk>
+    fn partial_cmp_by<I, F>(self, other: I, partial_cmp: F) -> Option<Ordering>
     where
         Self: Sized,
-        U: ~const IntoContainedBy<Self>
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>,
+        F: ~const FnMut(Self::Item, I::Item) -> Option<Ordering> + ~const Destruct
     {
-        crate::zip(self, other)
+        let other = other.into_bulk();
+        let by_length = length::value::cmp(self.length(), other.length());
+        match compare_by(self, other, partial_cmp, Some(Ordering::Equal))
+        {
+            Some(ordering) => ordering,
+            None => Some(by_length)
+        }
     }
 
-    /// Merges two bulks or iterators into a single bulk using a merging function.
-    /// 
-    /// Similar to [`Bulk::zip`], followed by [`Bulk::map`], but keeps the tail if the length of the two bulks differ.
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// less than those of another.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// use core::ops::Add;
-    /// 
-    /// let s1 = b"abc".into_bulk().copied();
-    /// let s2 = b"defg".into_bulk().copied();
     ///
-    /// let mut bulk = s1.merge(s2, Add::add);
-    /// 
-    /// let s: [_; _] = bulk.collect_array();
-    /// 
-    /// assert_eq!(s, [b'a' + b'd', b'b' + b'e', b'c' + b'f', b'g']);
+    /// assert!([1, 2].into_bulk().lt([1, 3]));
+    /// assert!([1].into_bulk().lt([1, 2]));
+    /// assert!(![1, 2].into_bulk().lt([1, 2]));
     /// ```
-    #[inline]
-    #[track_caller]
-    fn merge<U, F, O>(self, other: U, merger: F) -> Merge<Self, U::IntoBulk, F>
+    fn lt<I>(self, other: I) -> bool
     where
         Self: Sized,
-        U: ~const IntoBulk,
-        Self::Item: Into<O>,
-        U::Item: Into<O>,
-        F: FnMut(Self::Item, U::Item) -> O
+        Self::Item: ~const PartialOrd<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>
     {
-        crate::merge(self, other, merger)
+        matches!(self.partial_cmp(other), Some(Ordering::Less))
     }
 
-    /// Creates a new bulk which places a copy of `separator` between adjacent
-    /// items of the original bulk.
-    /// 
-    /// Similar to [`Iterator::intersperse`].
-    ///
-    /// In case `separator` does not implement [`Clone`](core::clone::Clone) or needs to be
-    /// computed every time, use [`intersperse_with`](Bulk::intersperse_with).
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// less than those of another, with respect to the specified comparison function.
+    fn lt_by<I, F>(self, other: I, partial_cmp: F) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>,
+        F: ~const FnMut(Self::Item, I::Item) -> Option<Ordering> + ~const Destruct
+    {
+        matches!(self.partial_cmp_by(other, partial_cmp), Some(Ordering::Less))
+    }
+
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// less or equal to those of another.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let mut a: [_; _] = [0, 1, 2].into_bulk().intersperse(100).collect();
-    /// 
-    /// assert_eq!(a, [0, 100, 1, 100, 2]);
+    ///
+    /// assert!([1, 2].into_bulk().le([1, 2]));
+    /// assert!(![1, 3].into_bulk().le([1, 2]));
     /// ```
+    fn le<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const PartialOrd<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>
+    {
+        matches!(self.partial_cmp(other), Some(Ordering::Less | Ordering::Equal))
+    }
+
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// less or equal to those of another, with respect to the specified comparison function.
+    fn le_by<I, F>(self, other: I, partial_cmp: F) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>,
+        F: ~const FnMut(Self::Item, I::Item) -> Option<Ordering> + ~const Destruct
+    {
+        matches!(self.partial_cmp_by(other, partial_cmp), Some(Ordering::Less | Ordering::Equal))
+    }
+
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// greater than those of another.
+    ///
+    /// # Examples
     ///
-    /// `intersperse` can be very useful to join a bulk's items using a common element:
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let words = ["Hello", "World", "!"];
-    /// let hello: String = words.into_bulk().intersperse(" ").collect();
-    /// assert_eq!(hello, "Hello World !");
+    /// assert!([1, 3].into_bulk().gt([1, 2]));
+    /// assert!(![1].into_bulk().gt([1, 2]));
     /// ```
-    #[inline]
-    #[track_caller]
-    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    fn gt<I>(self, other: I) -> bool
     where
         Self: Sized,
-        Self::Item: Clone,
+        Self::Item: ~const PartialOrd<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>
     {
-        Intersperse::new(self, separator)
+        matches!(self.partial_cmp(other), Some(Ordering::Greater))
     }
 
-    /// Creates a new bulk which places an item generated by `separator`
-    /// between adjacent items of the original bulk.
-    ///
-    /// The closure will be called exactly once each time an item is placed
-    /// between two adjacent items from the underlying bulk; specifically,
-    /// the closure is not called if the underlying bulk has less than
-    /// two items.
-    /// 
-    /// Similar to [`Iterator::intersperse_with`].
-    ///
-    /// If the bulk's item implements [`Clone`](core::clone::Clone), it may be easier to use
-    /// [`intersperse`](Bulk::intersperse).
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// greater than those of another, with respect to the specified comparison function.
+    fn gt_by<I, F>(self, other: I, partial_cmp: F) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>,
+        F: ~const FnMut(Self::Item, I::Item) -> Option<Ordering> + ~const Destruct
+    {
+        matches!(self.partial_cmp_by(other, partial_cmp), Some(Ordering::Greater))
+    }
+
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// greater or equal to those of another.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// #[derive(PartialEq, Debug)]
-    /// struct NotClone(usize);
+    /// assert!([1, 2].into_bulk().ge([1, 2]));
+    /// assert!(![1, 2].into_bulk().ge([1, 3]));
+    /// ```
+    fn ge<I>(self, other: I) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const PartialOrd<I::Item> + ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>
+    {
+        matches!(self.partial_cmp(other), Some(Ordering::Greater | Ordering::Equal))
+    }
+
+    /// Determines if the elements of this bulk are [lexicographically](Ord#lexicographical-comparison)
+    /// greater or equal to those of another, with respect to the specified comparison function.
+    fn ge_by<I, F>(self, other: I, partial_cmp: F) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<IntoBulk: ~const IntoCursor<Item: ~const Destruct>>,
+        F: ~const FnMut(Self::Item, I::Item) -> Option<Ordering> + ~const Destruct
+    {
+        matches!(self.partial_cmp_by(other, partial_cmp), Some(Ordering::Greater | Ordering::Equal))
+    }
+
+    /// Checks if the elements of this bulk are sorted.
     ///
-    /// let v = [NotClone(0), NotClone(1), NotClone(2)];
-    /// let u: [_; _] = v.into_bulk().intersperse_with(|| NotClone(99)).collect();
+    /// That is, for each element `a` and its following element `b`, `a <= b` must hold. If the
+    /// bulk yields exactly zero or one element, `true` is returned.
     ///
-    /// assert_eq!(u, [NotClone(0), NotClone(99), NotClone(1), NotClone(99), NotClone(2)]);
-    /// ```
+    /// Note that if `Self::Item` is only `PartialOrd`, but not `Ord`, the above definition
+    /// implies that this function returns `false` if any two consecutive items are not
+    /// comparable.
     ///
-    /// [`intersperse_with`](Bulk::intersperse_with) can be used in situations where the separator needs
-    /// to be computed:
-    /// ```
-    /// # #![feature(generic_const_exprs)]
-    /// use bulks::*;
+    /// `is_sorted()` is short-circuiting; it stops at the first pair of elements that is out of order.
     ///
-    /// let src = ["Hello", "to", "all", "people", "!!"].bulk().copied();
+    /// # Examples
     ///
-    /// // The closure mutably borrows its context to generate an item.
-    /// let mut happy_emojis = [" ❤️ ", " 😀 "].into_iter();
-    /// let separator = || happy_emojis.next().unwrap_or(" 🦀 ");
+    /// ```
+    /// use bulks::*;
     ///
-    /// let result: String = src.intersperse_with(separator).collect();
-    /// 
-    /// assert_eq!(result, "Hello ❤️ to 😀 all 🦀 people 🦀 !!");
+    /// assert!([1, 2, 2, 9].into_bulk().is_sorted());
+    /// assert!(![1, 3, 2, 4].into_bulk().is_sorted());
+    /// assert!([0].into_bulk().is_sorted());
+    /// assert!(<[i32; 0]>::default().into_bulk().is_sorted());
+    /// assert!(![0.0, 1.0, f32::NAN].into_bulk().is_sorted());
     /// ```
+    #[allow(clippy::wrong_self_convention)]
     #[inline]
-    #[track_caller]
-    fn intersperse_with<G>(self, separator: G) -> IntersperseWith<Self, G>
+    fn is_sorted(self) -> bool
     where
         Self: Sized,
-        G: FnMut() -> Self::Item,
+        Self::Item: ~const PartialOrd + ~const Destruct
     {
-        IntersperseWith::new(self, separator)
+        const fn le<T>(a: &T, b: &T) -> bool
+        where
+            T: ~const PartialOrd
+        {
+            a <= b
+        }
+
+        self.is_sorted_by(le)
     }
 
-    /// Takes a closure and creates a bulk which calls that closure on each
-    /// element.
+    /// Checks if the elements of this bulk are sorted, and contain no duplicates.
     ///
-    /// [`map()`](Bulk::map) transforms one bulk into another, by means of its argument:
-    /// something that implements [`FnMut`]. It produces a new bulk which
-    /// calls this closure on each element of the original bulk.
-    /// 
-    /// Similar to [`Iterator::map`].
+    /// That is, for each element `a` and its following element `b`, `a < b` must hold.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
     /// use bulks::*;
-    /// 
-    /// let a = [1, 2, 3];
-    ///
-    /// let mut b: [_; _] = a.bulk().map(|x| 2 * x).collect();
     ///
-    /// assert_eq!(b, [2, 4, 6]);
+    /// assert!([1, 2, 3, 9].into_bulk().is_strictly_sorted());
+    /// assert!(![1, 2, 2, 9].into_bulk().is_strictly_sorted());
     /// ```
+    #[allow(clippy::wrong_self_convention)]
+    #[inline]
+    fn is_strictly_sorted(self) -> bool
+    where
+        Self: Sized,
+        Self::Item: ~const PartialOrd + ~const Destruct
+    {
+        const fn lt<T>(a: &T, b: &T) -> bool
+        where
+            T: ~const PartialOrd
+        {
+            a < b
+        }
+
+        self.is_sorted_by(lt)
+    }
+
+    /// Checks if the elements of this bulk are sorted using the given comparator function.
     ///
-    /// If you're doing some sort of side effect, prefer [`for`] to [`map()`](Bulk::map):
+    /// Instead of using `PartialOrd::partial_cmp`, this function uses the given `compare`
+    /// function to determine whether two elements are to be considered in sorted order.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// # #![allow(unused_must_use)]
     /// use bulks::*;
-    /// 
-    /// // don't do this:
-    /// (0..5).into_bulk().map(|x| println!("{x}"));
     ///
-    /// // it won't even execute, as it is lazy. Rust will warn you about this.
+    /// assert!([1, 2, 2, 9].into_bulk().is_sorted_by(|a, b| a <= b));
+    /// assert!(![1, 2, 2, 9].into_bulk().is_sorted_by(|a, b| a < b));
     ///
-    /// // Instead, use a for-loop:
-    /// for x in (0..5).into_bulk()
-    /// {
-    ///     println!("{x}");
-    /// }
+    /// assert!([0].into_bulk().is_sorted_by(|_, _| true));
+    /// assert!([0].into_bulk().is_sorted_by(|_, _| false));
     /// ```
-    /// 
-    /// [`for`]: ../../book/ch03-05-control-flow.html#looping-through-a-collection-with-for
-    #[inline]
-    #[track_caller]
-    fn map<B, F>(self, f: F) -> Map<Self, F>
+    #[allow(clippy::wrong_self_convention)]
+    fn is_sorted_by<F>(self, compare: F) -> bool
     where
         Self: Sized,
-        F: FnMut(Self::Item) -> B,
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(&Self::Item, &Self::Item) -> bool + ~const Destruct
     {
-        Map::new(self, f)
+        struct Functor<T, F>
+        {
+            last: Option<T>,
+            compare: F
+        }
+        const impl<T, F> FnOnce<(T,)> for Functor<T, F>
+        where
+            T: ~const Destruct,
+            F: ~const FnMut(&T, &T) -> bool + ~const Destruct
+        {
+            type Output = ControlFlow<()>;
+
+            extern "rust-call" fn call_once(mut self, args: (T,)) -> Self::Output
+            {
+                self.call_mut(args)
+            }
+        }
+        const impl<T, F> FnMut<(T,)> for Functor<T, F>
+        where
+            T: ~const Destruct,
+            F: ~const FnMut(&T, &T) -> bool
+        {
+            extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+            {
+                if let Some(last) = &self.last
+                    && !(self.compare)(last, &x)
+                {
+                    return ControlFlow::Break(())
+                }
+                self.last = Some(x);
+                ControlFlow::Continue(())
+            }
+        }
+
+        matches!(self.try_for_each(Functor { last: None, compare }), ControlFlow::Continue(()))
     }
 
-    /// Creates a bulk which gives the current index together with its values.
-    ///
-    /// The bulk returned yields pairs `(i, val)`, where `i` is the
-    /// current index of iteration and `val` is its corresponding value.
-    /// 
-    /// Similar to [`Iterator::enumerate`].
-    ///
-    /// [`enumerate()`](Bulk::enumerate) keeps its count as a [`usize`]. If you want to count by a
-    /// different sized integer, use [`enumerate_from`](Bulk::enumerate_from) instead.
-    ///
-    /// # Overflow Behavior
-    ///
-    /// The method does no guarding against overflows, so enumerating more than
-    /// [`usize::MAX`] elements either produces the wrong result or panics. If
-    /// overflow checks are enabled, a panic is guaranteed.
+    /// Checks if the elements of this bulk are sorted using the given key extraction
+    /// function.
     ///
-    /// # Panics
+    /// Instead of comparing the bulk's elements directly, this function compares the keys of
+    /// the elements, as determined by `f`. Apart from that, it's equivalent to [`is_sorted`]; see
+    /// its documentation for more information.
     ///
-    /// The returned bulk might panic if the to-be-returned index would
-    /// overflow a [`usize`].
+    /// [`is_sorted`]: Bulk::is_sorted
     ///
     /// # Examples
     ///
     /// ```
     /// use bulks::*;
-    /// 
-    /// let a = ['a', 'b', 'c'];
-    ///
-    /// let b = a.into_bulk()
-    ///     .enumerate()
-    ///     .collect_array();
     ///
-    /// assert_eq!(b, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// assert!(["c", "bb", "aaa"].into_bulk().is_sorted_by_key(|s| s.len()));
+    /// assert!(![-2i32, -1, 0, 3].into_bulk().is_sorted_by_key(|n| n.abs()));
     /// ```
+    #[allow(clippy::wrong_self_convention)]
     #[inline]
-    #[track_caller]
-    fn enumerate(self) -> Enumerate<Self>
+    fn is_sorted_by_key<F, K>(self, f: F) -> bool
     where
-        Self: Sized
+        Self: Sized,
+        F: ~const FnMut(Self::Item) -> K + ~const Destruct,
+        K: ~const PartialOrd + ~const Destruct,
+        Map<Self, F>: ~const Bulk<Item = K>
     {
-        Enumerate::new(self)
+        self.map(f).is_sorted()
     }
-
-    /// Creates a bulk which gives the current index counting from a given initial index together with its values.
-    ///
-    /// The bulk returned yields pairs `(i, val)`, where `i` is the
-    /// current index of iteration and `val` is its corresponding value.
+    
+    /// Creates a bulk starting at the same point, but stepping by
+    /// the given amount at each iteration.
     /// 
-    /// This is similar to [`Bulk::enumerate`], except here a different type and initial value for counting can be used.
-    /// For counting an [`usize`] from 0 and up, [`Bulk::enumerate`] is a better alternative.
-    ///
-    /// # Overflow Behavior
-    ///
-    /// The method does no guarding against overflows, so enumerating more elements than supported values of `U`
-    /// either produces the wrong result or panics. If
-    /// overflow checks are enabled, a panic will happen depending how [`Step::forward`] is implemented for `U`.
+    /// Similar to [`Iterator::step_by`].
     ///
     /// # Panics
     ///
-    /// The returned bulk might panic if the to-be-returned index would
-    /// overflow.
+    /// The method will panic if the given step is `0`.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let a = ['a', 'b', 'c'];
-    ///
-    /// let b = a.into_bulk()
-    ///     .enumerate_from(1)
-    ///     .collect_array();
+    /// let a = [0, 1, 2, 3, 4, 5];
+    /// 
+    /// let mut bulk = a.into_bulk().step_by([(); 2]);
+    /// let a_even: [_; _] = bulk.collect();
     ///
-    /// assert_eq!(b, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// assert_eq!(a_even, [0, 2, 4]);
+    /// 
+    /// let mut bulk = a.into_bulk().skip([(); 1]).step_by([(); 2]);
+    /// let a_odd: [_; _] = bulk.collect();
+    /// 
+    /// assert_eq!(a_odd, [1, 3, 5]);
     /// ```
     #[inline]
     #[track_caller]
-    fn enumerate_from<U>(self, initial_count: U) -> EnumerateFrom<Self, U>
+    fn step_by<L>(self, step: L) -> StepBy<Self, L::Length<()>>
     where
         Self: Sized,
-        U: Step + Copy
+        L: LengthValue
     {
-        EnumerateFrom::new(self, initial_count)
+        StepBy::new(self, step)
     }
 
-    /// Creates a bulk that skips the first `n` elements.
-    /// 
-    /// Similar to [`Iterator::skip`].
+    /// Takes two bulks and creates a new bulk over both in sequence.
     ///
-    /// [`skip(n)`](Bulk::skip) skips elements until `n` elements are skipped or the end of the
-    /// bulk is reached (whichever happens first). The returned bulk will yield the remaining elements.
-    /// If the original bulk is too short, then the returned bulk is empty.
+    /// In other words, it links two bulks together, in a chain. 🔗
+    /// 
+    /// Similar to [`Iterator::chain`].
     ///
     /// # Examples
     ///
+    /// Basic usage:
+    ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let a = [1, 2, 3];
+    /// let s1 = b"abc";
+    /// let s2 = b"def";
     ///
-    /// let b: [_; _] = a.into_bulk().skip([(); 2]).collect();
-    /// let c: Vec<_> = a.into_bulk().skip(2).collect();
+    /// let mut bulk = s1.into_bulk()
+    ///     .chain(s2)
+    ///     .copied();
+    /// 
+    /// let s: [_; _] = bulk.collect();
+    /// 
+    /// assert_eq!(s, *b"abcdef");
+    /// ```
     ///
-    /// assert_eq!(b, [3]);
-    /// assert_eq!(c, [3]);
+    /// Since the argument to [`chain()`](Bulk::chain) uses [`IntoBulk`], we can pass
+    /// anything that can be converted into a [`Bulk`], not just a
+    /// [`Bulk`] itself. For example, arrays (`[T; _]`) implement
+    /// [`IntoBulk`], and so can be passed to [`chain()`](Bulk::chain) directly:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let a1 = [1, 2, 3];
+    /// let a2 = [4, 5, 6];
+    ///
+    /// let mut bulk = a1.into_bulk()
+    ///     .chain(a2);
+    /// 
+    /// let a: [_; _] = bulk.collect();
+    /// 
+    /// assert_eq!(a, [1, 2, 3, 4, 5, 6]);
     /// ```
     #[inline]
     #[track_caller]
-    fn skip<L>(self, n: L) -> Skip<Self, L::Length<()>>
+    fn chain<U>(self, other: U) -> Chain<Self, U::IntoBulk>
     where
         Self: Sized,
-        L: LengthValue
+        U: ~const IntoBulk<Item = Self::Item>,
     {
-        Skip::new(self, n)
+        Chain::new(self, other.into_bulk())
     }
 
-    /// Creates a bulk for the first `n` elements, or fewer
-    /// if the underlying bulk/iterator is shorter.
+    /// Repeats the whole bulk `n` times.
     ///
-    /// [`take(n)`](Bulk::take) yields elements until `n` elements are yielded or the end of the
-    /// bulk is reached (whichever happens first).
-    /// The returned bulk is a prefix of length `n` if the original bulk/iterator
-    /// contains at least `n` elements, otherwise it contains all of the
-    /// (fewer than `n`) elements of the original bulk/iterator.
+    /// Unlike [`Iterator::cycle`], the bulk is only repeated a finite number of times, so the length is
+    /// the length of the original bulk multiplied by `n`. If both are known at compile-time, so is the resulting length.
     ///
-    /// # Examples
+    /// The bulk is cloned for every repetition, except the last one.
     ///
-    /// Basic usage:
+    /// # Examples
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a = [1, 2, 3];
-    ///
-    /// let b: Vec<_> = a.into_bulk().take([(); 2]).collect();
-    ///
-    /// assert_eq!(b, [1, 2]);
-    /// ```
-    ///
-    /// `take()` is often used with an infinite iterator, to make it finite:
-    ///
-    /// ```
-    /// let a: Vec<_> = (0..).take(3).collect();
     ///
-    /// assert_eq!(a, [0, 1, 2])
-    /// ```
+    /// let a = [1, 2, 3];
     ///
-    /// If less than `n` elements are available,
-    /// [`take`](Bulk::take) will limit itself to the size of the underlying bulk/iterator:
+    /// let b: [_; _] = a.into_bulk()
+    ///     .cycle_n([(); 3])
+    ///     .collect();
     ///
+    /// assert_eq!(b, [1, 2, 3, 1, 2, 3, 1, 2, 3]);
     /// ```
-    /// # #![feature(generic_const_exprs)]
-    /// use bulks::*;
-    /// 
-    /// let v = [1, 2];
-    /// let b: [_; _] = v.into_bulk().take([(); 5]).collect();
-    /// 
-    /// assert_eq!(b, [1, 2])
-    /// ```
-    #[doc(alias = "limit")]
     #[inline]
     #[track_caller]
-    fn take<L>(self, n: L) -> Take<Self, L::Length<()>>
+    fn cycle_n<L>(self, n: L) -> CycleN<Self, L::Length<()>>
     where
-        Self: Sized,
+        Self: Sized + Clone,
         L: LengthValue
     {
-        Take::new(self, n)
+        CycleN::new(self, n)
     }
 
-    /// Creates a bulk that works like map, but flattens nested structure.
+    /// Pairs up every element of the bulk with every element of another bulk.
     ///
-    /// The [`map`](Bulk::map) adapter is very useful, but only when the closure
-    /// argument produces values. If it produces something iterable instead, there's
-    /// an extra layer of indirection. [`flat_map()`](Bulk::flat_map) will remove this extra layer
-    /// on its own.
-    /// 
-    /// Similar to [`Iterator::flat_map`].
+    /// The elements of `self` make up the outer loop, and the elements of `other` the inner loop,
+    /// so `other` is cloned once for every element of `self`.
     ///
-    /// You can think of `flat_map(f)` as the semantic equivalent
-    /// of [`map`](Bulk::map)ping, and then [`flatten`](Bulk::flatten)ing as in `map(f).flatten()`.
+    /// The length of the resulting bulk is the product of the lengths of the two bulks.
+    /// If both are known at compile-time, so is the resulting length.
     ///
     /// # Examples
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let words = [b"alpha", b"beta ", b"gamma"];
     ///
-    /// let merged: String = words.into_bulk()
-    ///     .flat_map(|&s| s.into_bulk().map(|b| char::from(b)))
+    /// let xs = [1, 2, 3];
+    /// let ys = ['a', 'b'];
+    ///
+    /// let grid: [_; 6] = xs.into_bulk()
+    ///     .cartesian_product(ys)
     ///     .collect();
-    /// assert_eq!(merged, "alphabeta gamma");
+    ///
+    /// assert_eq!(grid, [(1, 'a'), (1, 'b'), (2, 'a'), (2, 'b'), (3, 'a'), (3, 'b')]);
     /// ```
     #[inline]
-    #[track_caller]
-    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, F>
+    fn cartesian_product<U>(self, other: U) -> CartesianProduct<Self, U::IntoBulk>
     where
         Self: Sized,
-        U: IntoBulk<IntoBulk: StaticBulk>,
-        F: FnMut(Self::Item) -> U,
+        Self::Item: Clone,
+        U: ~const IntoBulk<IntoBulk: Clone>
     {
-        FlatMap::new(self, f)
+        crate::cartesian_product(self, other)
     }
 
-    /// Creates a bulk that flattens nested structure.
-    ///
-    /// This is useful when you have a bulk of bulk or a bulk of
-    /// things that can be turned into bulks and you want to remove one
-    /// level of indirection.
+    /// 'Zips up' two bulks or iterators into a single bulk of pairs. One of them must be a bulk.
     /// 
-    /// Similar to [`Iterator::flatten`].
+    /// Similar to [`Iterator::zip`].
     ///
     /// # Examples
     ///
@@ -1695,897 +1836,2799 @@ pub const trait Bulk: ~const IntoBulk<IntoBulk = Self>
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let data = [[1, 2, 3], [4, 5, 6]];
-    /// let flattened: [_; _] = data.into_bulk().flatten().collect();
-    /// assert_eq!(flattened, [1, 2, 3, 4, 5, 6]);
+    /// let s1 = b"abc".into_bulk().copied();
+    /// let s2 = b"def".into_bulk().copied();
+    ///
+    /// let mut bulk = s1.zip(s2);
+    /// 
+    /// let s: [_; _] = bulk.collect();
+    /// 
+    /// assert_eq!(s, [(b'a', b'd'), (b'b', b'e'), (b'c', b'f')]);
     /// ```
     ///
-    /// Mapping and then flattening:
+    /// Since the argument to [`zip()`](Bulk::zip) uses [`IntoBulk`], we can pass
+    /// anything that can be converted into a [`Bulk`], not just a
+    /// [`Bulk`] itself. For example, arrays (`[T]`) implement
+    /// [`IntoBulk`], and so can be passed to [`zip()`](Bulk::zip) directly:
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let words = [b"alpha", b"beta ", b"gamma"];
+    /// let a1 = [1, 2, 3];
+    /// let a2 = [4, 5, 6];
     ///
-    /// let merged: String = words.into_bulk()
-    ///     .map(|&s| s.into_bulk().map(|b| char::from(b)))
-    ///     .flatten()
-    ///     .collect();
-    /// assert_eq!(merged, "alphabeta gamma");
+    /// let mut bulk = a1.into_bulk().zip(a2);
+    ///
+    /// let a: [_; _] = bulk.collect();
+    /// 
+    /// assert_eq!(a, [(1, 4), (2, 5), (3, 6)]);
     /// ```
     ///
-    /// You can also rewrite this in terms of [`flat_map()`](Bulk::flat_map), which is preferable
-    /// in this case since it conveys intent more clearly:
+    /// `zip()` is often used to zip an infinite iterator to a finite one.
+    /// This works because the finite iterator will eventually return [`None`],
+    /// ending the zipper. Zipping with `(0..)` can look a lot like [`enumerate`]:
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let words = [b"alpha", b"beta ", b"gamma"];
+    /// let enumerate: [_; _] = (*b"foo").into_bulk().enumerate().collect();
     ///
-    /// let merged: String = words.into_bulk()
-    ///     .flat_map(|&s| s.into_bulk().map(|b| char::from(b)))
-    ///     .collect();
-    /// assert_eq!(merged, "alphabeta gamma");
+    /// let zipper: Vec<_> = bulks::rzip(0.., *b"foo").collect();
+    /// 
+    /// assert_eq!((0, b'f'), enumerate[0]);
+    /// assert_eq!((0, b'f'), zipper[0]);
+    /// 
+    /// assert_eq!((1, b'o'), enumerate[1]);
+    /// assert_eq!((1, b'o'), zipper[1]);
+    /// 
+    /// assert_eq!((2, b'o'), enumerate[2]);
+    /// assert_eq!((2, b'o'), zipper[2]);
     /// ```
     ///
-    /// Flattening only removes one level of nesting at a time:
+    /// It can be more readable to use [`bulks::zip`](crate::zip):
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let d3 = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
     ///
-    /// let d2: [_; _] = d3.into_bulk().flatten().collect();
-    /// assert_eq!(d2, [[1, 2], [3, 4], [5, 6], [7, 8]]);
+    /// let a = [1, 2, 3];
+    /// let b = [2, 3, 4];
     ///
-    /// let d1: [_; _] = d3.into_bulk().flatten().flatten().collect();
-    /// assert_eq!(d1, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let mut zipped = bulks::zip(
+    ///     a.into_bulk().map(|x| x * 2).skip([(); 1]),
+    ///     b.into_bulk().map(|x| x * 2).skip([(); 1]),
+    /// );
+    /// 
+    /// let c: [_; _] = zipped.collect();
+    /// 
+    /// assert_eq!(c, [(4, 6), (6, 8)]);
     /// ```
     ///
-    /// Here we see that [`flatten()`](Bulk::flatten) does not perform a "deep" flatten.
-    /// Instead, only one level of nesting is removed. That is, if you
-    /// [`flatten()`](Bulk::flatten) a three-dimensional array, the result will be
-    /// two-dimensional and not one-dimensional. To get a one-dimensional
-    /// structure, you have to [`flatten()`](Bulk::flatten) again.
+    /// compared to:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// # use bulks::*;
+    /// #
+    /// # let a = [1, 2, 3];
+    /// # let b = [2, 3, 4];
+    /// #
+    /// let mut zipped = a.into_bulk()
+    ///     .map(|x| x * 2)
+    ///     .skip([(); 1])
+    ///     .zip(b.into_bulk()
+    ///         .map(|x| x * 2)
+    ///         .skip([(); 1])
+    ///     );
+    /// #
+    /// # let c: [_; _] = zipped.collect();
+    /// # assert_eq!(c, [(4, 6), (6, 8)]);
+    /// ```
     #[inline]
     #[track_caller]
-    fn flatten(self) -> Flatten<Self>
+    fn zip<U>(self, other: U) -> Zip<Self, <<U as IntoContained>::IntoContained as IntoBulk>::IntoBulk>
     where
         Self: Sized,
-        Self::Item: IntoBulk<IntoBulk: StaticBulk>,
+        U: ~const IntoContainedBy<Self>
     {
-        Flatten::new(self)
+        crate::zip(self, other)
     }
 
-    /// Calls the given function `f` for each contiguous window of size `N` over
-    /// `self` and returns a bulk of the outputs of `f`. The windows during mapping will overlap.
-    /// 
-    /// Similar to [`Iterator::map_windows`].
+    /// Zips up two bulks of the same length.
     ///
-    /// In the following example, the closure is called three times with the
-    /// arguments `&['a', 'b']`, `&['b', 'c']` and `&['c', 'd']` respectively.
+    /// Similar to [`Bulk::zip`], but requires both bulks to be of the same length, instead of quietly taking the shortest one.
+    ///
+    /// If both bulks have a length known at compile-time, mismatching lengths is a compile error.
+    /// Otherwise, the lengths are compared at runtime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of the bulks differ.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let strings: [_; _] = b"abcd".bulk()
-    ///     .map(|&c| char::from(c))
-    ///     .map_windows(|[x, y]| format!("{}+{}", x, y))
+    /// let a1 = [1, 2, 3];
+    /// let a2 = [4, 5, 6];
+    ///
+    /// let zipped: [_; _] = a1.into_bulk()
+    ///     .zip_exact(a2)
     ///     .collect();
     ///
-    /// assert_eq!(strings, ["a+b", "b+c", "c+d"]);
+    /// assert_eq!(zipped, [(1, 4), (2, 5), (3, 6)]);
     /// ```
     ///
-    /// Note that the const parameter `N` is usually inferred by the
-    /// destructured argument in the closure.
-    ///
-    /// The returned bulk yields 𝑘 − `N` + 1 items (where 𝑘 is the number of
-    /// items yielded by `self`). If 𝑘 is less than `N`, this method yields an
-    /// empty bulk.
-    ///
-    /// # Panics
-    ///
-    /// Panics if `N` is zero.
+    /// Mismatching lengths only known at runtime panic:
     ///
     /// ```should_panic
     /// use bulks::*;
     ///
-    /// let bulk = [0].into_bulk().map_windows(|&[]| ());
-    /// ```
-    ///
-    /// # Examples
-    ///
-    /// Building the sums of neighboring numbers.
+    /// let a1 = [1, 2, 3];
+    /// let a2: &[_] = &[4, 5];
     ///
+    /// let zipped = a1.into_bulk()
+    ///     .zip_exact(a2);
     /// ```
-    /// # #![feature(generic_const_exprs)]
-    /// use bulks::*;
+    #[inline]
+    #[track_caller]
+    fn zip_exact<U>(self, other: U) -> Zip<Self, U::IntoBulk>
+    where
+        Self: Sized + ~const Destruct + SameLength<U::IntoBulk>,
+        U: ~const IntoBulk<IntoBulk: ~const Destruct>
+    {
+        crate::zip_exact(self, other)
+    }
+
+    /// Zips up two bulks of the same length.
     ///
-    /// let w: [_; _] = [1, 3, 8, 1].bulk()
-    ///     .map_windows(|&[a, b]| a + b)
-    ///     .collect();
-    /// 
-    /// assert_eq!(w, [1 + 3, 3 + 8, 8 + 1]);
-    /// ```
+    /// Similar to [`Bulk::zip_exact`], but returns an error instead of panicking if the lengths of the bulks differ.
     ///
-    /// Since the elements in the following example implement [`Copy`], we can
-    /// just copy the array and get a bulk of the windows.
+    /// # Examples
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let w: [[_; _]; _] = b"ferris".bulk()
-    ///     .map_windows(|w: &[_; 3]| w.bulk()
-    ///         .copied()
-    ///         .copied()
-    ///         .collect())
-    ///     .collect();
-    /// 
-    /// assert_eq!(w, [[b'f', b'e', b'r'], [b'e', b'r', b'r'], [b'r', b'r', b'i'], [b'r', b'i', b's']]);
+    /// let a1 = [1, 2, 3];
+    /// let a2: &[_] = &[4, 5];
+    ///
+    /// assert!(matches!(
+    ///     a1.into_bulk().try_zip_exact(a2),
+    ///     Err(LengthMismatch { lhs: 3, rhs: 2 })
+    /// ));
     /// ```
+    #[inline]
+    fn try_zip_exact<U>(self, other: U) -> Result<Zip<Self, U::IntoBulk>, LengthMismatch>
+    where
+        Self: Sized + ~const Destruct + SameLength<U::IntoBulk>,
+        U: ~const IntoBulk<IntoBulk: ~const Destruct>
+    {
+        crate::try_zip_exact(self, other)
+    }
+
+    /// Zips up two bulks, continuing until both of them are exhausted.
     ///
-    /// You can also use this function to check the sortedness of a bulk.
-    /// For the simple case, rather use [`Bulk::is_sorted`].
+    /// Similar to [`Bulk::zip`], but instead of stopping at the end of the shortest bulk, it keeps going until the end of the longest one.
+    /// Each item is an [`EitherOrBoth`](crate::EitherOrBoth), telling whether both bulks yielded an element, or only one of them.
+    ///
+    /// The length of the resulting bulk is the maximum of the lengths of the two bulks.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let w: [_; _] = [0.5, 1.0, 3.5, 3.0, 8.5, 8.5, f32::NAN].bulk()
-    ///     .map_windows(|[a, b]| a <= b)
+    /// let a1 = [1, 2];
+    /// let a2 = [4, 5, 6];
+    ///
+    /// let zipped: [_; _] = a1.into_bulk()
+    ///     .zip_longest(a2)
     ///     .collect();
-    /// 
-    /// assert_eq!(w, [true, true, false, true, true, false]);
+    ///
+    /// assert_eq!(zipped, [
+    ///     EitherOrBoth::Both(1, 4),
+    ///     EitherOrBoth::Both(2, 5),
+    ///     EitherOrBoth::Right(6)
+    /// ]);
     /// ```
     #[inline]
-    #[track_caller]
-    fn map_windows<F, R, const N: usize>(self, f: F) -> MapWindows<Self, F, N>
+    fn zip_longest<U>(self, other: U) -> ZipLongest<Self, U::IntoBulk>
     where
         Self: Sized,
-        F: FnMut(&[Self::Item; N]) -> R,
+        U: ~const IntoBulk
     {
-        MapWindows::new(self, f)
+        crate::zip_longest(self, other)
     }
 
-    /// Does something with each element of a bulk, passing the value on.
+    /// Interleaves two bulks of the same length, alternating between their elements.
     ///
-    /// When using bulks, you'll often chain several of them together.
-    /// While working on such code, you might want to check out what's
-    /// happening at various parts in the pipeline. To do that, insert
-    /// a call to [`inspect()`](Bulk::inspect).
-    /// 
-    /// Similar to [`Iterator::inspect`].
+    /// The first element comes from `self`, the second from `other`, and so on.
+    /// The length of the resulting bulk is twice the length of the two bulks,
+    /// so interleaving two [`StaticBulk`]s results in a [`StaticBulk`].
+    ///
+    /// This is the inverse of [`deinterleave`](Bulk::deinterleave) with two parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the lengths of the bulks differ.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a = [1, 4, 2, 3];
     ///
-    /// // this iterator sequence is complex.
-    /// let sum = a.bulk()
-    ///     .cloned()
-    ///     .map(|x| if x % 2 == 0 {Some(x)} else {None})
-    ///     .fold(0, |sum, i| sum + i.unwrap_or(0));
-    ///
-    /// println!("{sum}");
+    /// let a1 = [1, 3, 5];
+    /// let a2 = [2, 4, 6];
     ///
-    /// // let's add some inspect() calls to investigate what's happening
-    /// let sum = a.bulk()
-    ///     .cloned()
-    ///     .inspect(|x| println!("about to filter: {x}"))
-    ///     .map(|x| if x % 2 == 0 {Some(x)} else {None})
-    ///     .inspect(|x| if let Some(x) = x {println!("made it through filter: {x}")})
-    ///     .fold(0, |sum, i| sum + i.unwrap_or(0));
+    /// let interleaved: [_; _] = a1.into_bulk()
+    ///     .interleave(a2)
+    ///     .collect();
     ///
-    /// println!("{sum}");
+    /// assert_eq!(interleaved, [1, 2, 3, 4, 5, 6]);
     /// ```
+    #[inline]
+    #[track_caller]
+    fn interleave<U>(self, other: U) -> Interleave<Self, U::IntoBulk>
+    where
+        Self: Sized + ~const Destruct + SameLength<U::IntoBulk>,
+        U: ~const IntoBulk<Item = <Self as IntoIterator>::Item, IntoBulk: ~const Destruct>
+    {
+        crate::interleave(self, other)
+    }
+
+    /// Distributes the elements of the bulk round-robin into `K` bulks of equal length.
     ///
-    /// This will print:
+    /// The first element goes to the first part, the second element to the second part, and so on,
+    /// wrapping around after `K` elements. The bulk is only consumed once.
     ///
-    /// ```text
-    /// 6
-    /// about to filter: 1
-    /// about to filter: 4
-    /// made it through filter: 4
-    /// about to filter: 2
-    /// made it through filter: 2
-    /// about to filter: 3
-    /// 6
-    /// ```
+    /// If the bulk is a [`StaticBulk`], each of the parts is too.
     ///
-    /// Logging errors before discarding them:
+    /// This is the inverse of [`interleave`](Bulk::interleave) when `K` is two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the bulk is not a multiple of `K`. If the length is known at compile-time,
+    /// this is instead a compile-time error.
+    ///
+    /// # Examples
     ///
     /// ```
-    /// let lines = ["1", "2", "a"];
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
     ///
-    /// let sum: i32 = lines
-    ///     .iter()
-    ///     .map(|line| line.parse::<i32>())
-    ///     .inspect(|num| {
-    ///         if let Err(ref e) = *num {
-    ///             println!("Parsing error: {e}");
-    ///         }
-    ///     })
-    ///     .filter_map(Result::ok)
-    ///     .sum();
+    /// let a = [1, 2, 3, 4, 5, 6];
     ///
-    /// println!("Sum: {sum}");
+    /// let [x, y, z] = a.into_bulk().deinterleave::<3>();
+    ///
+    /// let x: [_; _] = x.collect();
+    /// let y: [_; _] = y.collect();
+    /// let z: [_; _] = z.collect();
+    ///
+    /// assert_eq!(x, [1, 4]);
+    /// assert_eq!(y, [2, 5]);
+    /// assert_eq!(z, [3, 6]);
     /// ```
     ///
-    /// This will print:
+    /// Bulks of a length known at compile-time that is not a multiple of `K` will not compile:
     ///
-    /// ```text
-    /// Parsing error: invalid digit found in string
-    /// Sum: 3
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3, 4, 5];
+    ///
+    /// let [_, _] = a.into_bulk().deinterleave::<2>();
     /// ```
+    #[allow(clippy::type_complexity)]
     #[inline]
     #[track_caller]
-    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn deinterleave<const K: usize>(self) -> [<length::Div<BulkLength<Self>, [(); K]> as UnzipBuffer>::Output<Self::Item>; K]
     where
-        Self: Sized,
-        F: FnMut(&Self::Item),
+        Self: Sized + DivisibleLength<K>,
+        Self::Item: ~const Destruct,
+        length::Div<BulkLength<Self>, [(); K]>: ~const UnzipBuffer
     {
-        Inspect::new(self, f)
+        crate::deinterleave::<Self, K>(self)
     }
 
-    /// Mutates with each element of a bulk, passing the value on.
+    /// Merges two bulks or iterators into a single bulk using a merging function.
+    /// 
+    /// Similar to [`Bulk::zip`], followed by [`Bulk::map`], but keeps the tail if the length of the two bulks differ.
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let a = [1, 4, 2, 3];
-    ///
-    /// // this iterator sequence is complex.
-    /// let b: [_; _] = a.into_bulk()
-    ///     .mutate(|x| *x += 1)
-    ///     .collect();
+    /// use core::ops::Add;
+    /// 
+    /// let s1 = b"abc".into_bulk().copied();
+    /// let s2 = b"defg".into_bulk().copied();
     ///
-    /// assert_eq!(b, [2, 5, 3, 4]);
+    /// let mut bulk = s1.merge(s2, Add::add);
+    /// 
+    /// let s: [_; _] = bulk.collect_array();
+    /// 
+    /// assert_eq!(s, [b'a' + b'd', b'b' + b'e', b'c' + b'f', b'g']);
     /// ```
     #[inline]
     #[track_caller]
-    fn mutate<F>(self, f: F) -> Mutate<Self, F>
+    fn merge<U, F, O>(self, other: U, merger: F) -> Merge<Self, U::IntoBulk, F>
     where
         Self: Sized,
-        F: FnMut(&mut Self::Item),
+        U: ~const IntoBulk,
+        Self::Item: Into<O>,
+        U::Item: Into<O>,
+        F: FnMut(Self::Item, U::Item) -> O
     {
-        Mutate::new(self, f)
+        crate::merge(self, other, merger)
     }
 
-    /// Transforms a bulk into a collection.
-    ///
-    /// [`collect()`](Bulk::collect) can take anything bulkable, and turn it into a relevant
-    /// collection.
+    /// Creates a new bulk which places a copy of `separator` between adjacent
+    /// items of the original bulk.
     /// 
-    /// Similar to [`Iterator::collect`].
+    /// Similar to [`Iterator::intersperse`].
+    ///
+    /// In case `separator` does not implement [`Clone`](core::clone::Clone) or needs to be
+    /// computed every time, use [`intersperse_with`](Bulk::intersperse_with).
     ///
     /// # Examples
     ///
     /// Basic usage:
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     /// 
-    /// let a = [1, 2, 3];
-    ///
-    /// let doubled: [i32; 3] = a.bulk()
-    ///     .map(|x| x * 2)
-    ///     .collect();
-    ///
-    /// assert_eq!(doubled, [2, 4, 6]);
+    /// let mut a: [_; _] = [0, 1, 2].into_bulk().intersperse(100).collect();
+    /// 
+    /// assert_eq!(a, [0, 100, 1, 100, 2]);
     /// ```
     ///
-    /// Note that we needed the `: [i32; 3]` on the left-hand side. This is because
-    /// we could collect into, for example, a [`VecDeque<T>`](std::collections::VecDeque) instead:
-    ///
+    /// `intersperse` can be very useful to join a bulk's items using a common element:
     /// ```
-    /// use std::collections::VecDeque;
-    /// 
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let a = [1, 2, 3];
-    ///
-    /// let doubled: VecDeque<i32> = a.bulk()
-    ///     .map(|x| x * 2)
-    ///     .collect();
-    ///
-    /// assert_eq!(doubled[0], 2);
-    /// assert_eq!(doubled[1], 4);
-    /// assert_eq!(doubled[2], 6);
+    /// let words = ["Hello", "World", "!"];
+    /// let hello: String = words.into_bulk().intersperse(" ").collect();
+    /// assert_eq!(hello, "Hello World !");
     /// ```
+    #[inline]
+    #[track_caller]
+    fn intersperse(self, separator: Self::Item) -> Intersperse<Self>
+    where
+        Self: Sized,
+        Self::Item: Clone,
+    {
+        Intersperse::new(self, separator)
+    }
+
+    /// Creates a new bulk which places an item generated by `separator`
+    /// between adjacent items of the original bulk.
     ///
-    /// Using the 'turbofish' instead of annotating `doubled`:
-    ///
-    /// ```
-    /// use bulks::*;
+    /// The closure will be called exactly once each time an item is placed
+    /// between two adjacent items from the underlying bulk; specifically,
+    /// the closure is not called if the underlying bulk has less than
+    /// two items.
     /// 
-    /// let a = [1, 2, 3];
+    /// Similar to [`Iterator::intersperse_with`].
     ///
-    /// let doubled = a.bulk()
-    ///     .map(|x| x * 2)
-    ///     .collect::<[i32; 3], _>();
+    /// If the bulk's item implements [`Clone`](core::clone::Clone), it may be easier to use
+    /// [`intersperse`](Bulk::intersperse).
     ///
-    /// assert_eq!(doubled, [2, 4, 6]);
-    /// ```
+    /// # Examples
     ///
-    /// Because `collect()` only cares about what you're collecting into, you can
-    /// still use a partial type hint, `_`, with the turbofish:
+    /// Basic usage:
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a = [1, 2, 3];
     ///
-    /// let doubled: [_; _] = a.bulk()
-    ///     .map(|x| x * 2)
-    ///     .collect();
+    /// #[derive(PartialEq, Debug)]
+    /// struct NotClone(usize);
     ///
-    /// assert_eq!(doubled, [2, 4, 6]);
-    /// ```
+    /// let v = [NotClone(0), NotClone(1), NotClone(2)];
+    /// let u: [_; _] = v.into_bulk().intersperse_with(|| NotClone(99)).collect();
     ///
-    /// Using `collect()` to make a [`String`](std::string::String):
+    /// assert_eq!(u, [NotClone(0), NotClone(99), NotClone(1), NotClone(99), NotClone(2)]);
+    /// ```
     ///
+    /// [`intersperse_with`](Bulk::intersperse_with) can be used in situations where the separator needs
+    /// to be computed:
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let chars = ['g', 'd', 'k', 'k', 'n'];
-    ///
-    /// let hello: String = chars.bulk()
-    ///     .copied()
-    ///     .map(|x| x as u8)
-    ///     .map(|x| (x + 1) as char)
-    ///     .collect();
     ///
-    /// assert_eq!(hello, "hello");
-    /// ```
+    /// let src = ["Hello", "to", "all", "people", "!!"].bulk().copied();
     ///
-    /// If you have a list of [`Result<T, E>`][`Result`]s, you can use `collect()` to
-    /// see if any of them failed:
+    /// // The closure mutably borrows its context to generate an item.
+    /// let mut happy_emojis = [" ❤️ ", " 😀 "].into_iter();
+    /// let separator = || happy_emojis.next().unwrap_or(" 🦀 ");
     ///
-    /// ```
-    /// use bulks::*;
+    /// let result: String = src.intersperse_with(separator).collect();
     /// 
-    /// let results = [Ok(1), Err("nope"), Ok(3), Err("bad")];
-    ///
-    /// let result: Result<[_; _], &str> = results.into_bulk().collect();
-    ///
-    /// // gives us the first error
-    /// assert_eq!(result, Err("nope"));
-    ///
-    /// let results = [Ok(1), Ok(3)];
-    ///
-    /// let result: Result<[_; _], &str> = results.into_bulk().collect();
-    ///
-    /// // gives us the list of answers
-    /// assert_eq!(result, Ok([1, 3]));
+    /// assert_eq!(result, "Hello ❤️ to 😀 all 🦀 people 🦀 !!");
     /// ```
     #[inline]
-    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
-    fn collect<C, A>(self) -> C
+    #[track_caller]
+    fn intersperse_with<G>(self, separator: G) -> IntersperseWith<Self, G>
     where
         Self: Sized,
-        C: ~const FromBulk<A>,
-        A: CollectionAdapter<Elem = Self::Item> + ~const CollectionStrategy<Self::MinLength, Self::MaxLength, C> + ?Sized
+        G: FnMut() -> Self::Item,
     {
-        FromBulk::from_bulk(self)
+        IntersperseWith::new(self, separator)
     }
 
-    /// Fallibly transforms a bulk into a collection, short circuiting if
-    /// a failure is encountered.
-    ///
-    /// `try_collect()` is a variation of [`collect()`][`Bulk::collect`] that allows fallible
-    /// conversions during collection. Its main use case is simplifying conversions from
-    /// iterators yielding [`Option<T>`][`Option`] into `Option<Collection<T>>`, or similarly for other [`Try`]
-    /// types (e.g. [`Result`]).
+    /// Takes a closure and creates a bulk which calls that closure on each
+    /// element.
     ///
-    /// Importantly, `try_collect()` doesn't require that the outer [`Try`] type also implements [`FromBulk`];
-    /// only the inner type produced on `Try::Output` must implement it. Concretely,
-    /// this means that collecting into `ControlFlow<_, Vec<i32>>` is valid because `Vec<i32>` implements
-    /// [`FromBulk`], even though [`ControlFlow`] doesn't.
+    /// [`map()`](Bulk::map) transforms one bulk into another, by means of its argument:
+    /// something that implements [`FnMut`]. It produces a new bulk which
+    /// calls this closure on each element of the original bulk.
     /// 
-    /// Unlike with [`Iterator::try_collect`], the bulk is fully consumed even if it short-circuits.
-    /// A short-circuit will cause the rest of the elements of the bulk to be dropped.
+    /// Similar to [`Iterator::map`].
     ///
     /// # Examples
-    /// 
-    /// Successfully collecting a bulk of `Option<i32>` into `Option<[i32; _]>`:
-    /// ```
-    /// use bulks::*;
-    /// 
-    /// let u = [Some(1), Some(2), Some(3)];
-    /// 
-    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
-    /// 
-    /// assert_eq!(v, Some([1, 2, 3]));
-    /// ```
     ///
-    /// Failing to collect in the same way:
-    /// ```
-    /// use bulks::*;
-    /// 
-    /// let u = [Some(1), Some(2), None, Some(3)];
-    /// 
-    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
-    /// 
-    /// assert_eq!(v, None);
-    /// ```
+    /// Basic usage:
     ///
-    /// A similar example, but with `Result`:
     /// ```
     /// use bulks::*;
     /// 
-    /// let u: [Result<i32, ()>; _] = [Ok(1), Ok(2), Ok(3)];
-    /// 
-    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
-    /// 
-    /// assert_eq!(v, Ok([1, 2, 3]));
+    /// let a = [1, 2, 3];
     ///
-    /// let u = [Ok(1), Ok(2), Err(()), Ok(3)];
-    /// 
-    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
-    /// 
-    /// assert_eq!(v, Err(()));
+    /// let mut b: [_; _] = a.bulk().map(|x| 2 * x).collect();
+    ///
+    /// assert_eq!(b, [2, 4, 6]);
     /// ```
     ///
-    /// Finally, even [`ControlFlow`] works, despite the fact that it
-    /// doesn't implement [`FromBulk`].
+    /// If you're doing some sort of side effect, prefer [`for`] to [`map()`](Bulk::map):
     ///
     /// ```
-    /// # #![feature(generic_const_exprs)]
+    /// # #![allow(unused_must_use)]
     /// use bulks::*;
     /// 
-    /// use core::ops::ControlFlow::{Break, Continue};
+    /// // don't do this:
+    /// (0..5).into_bulk().map(|x| println!("{x}"));
     ///
-    /// let u = [Continue(1), Continue(2), Break(3), Continue(4), Continue(5)];
-    /// 
-    /// let v = u.into_bulk().try_collect::<[_; _], _>();
-    /// 
-    /// assert_eq!(v, Break(3));
+    /// // it won't even execute, as it is lazy. Rust will warn you about this.
     ///
-    /// let v = u.into_bulk().take([(); 2])
-    ///     .chain(u.into_bulk().skip([(); 3]))
-    ///     .try_collect::<[_; _], _>();
-    /// 
-    /// assert_eq!(v, Continue([1, 2, 4, 5]));
+    /// // Instead, use a for-loop:
+    /// for x in (0..5).into_bulk()
+    /// {
+    ///     println!("{x}");
+    /// }
     /// ```
+    /// 
+    /// [`for`]: ../../book/ch03-05-control-flow.html#looping-through-a-collection-with-for
     #[inline]
-    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
-    fn try_collect<C, A>(self) -> <<Self::Item as Try>::Residual as Residual<C>>::TryType
+    #[track_caller]
+    fn map<B, F>(self, f: F) -> Map<Self, F>
     where
         Self: Sized,
-        C: ~const FromBulk<A>,
-        A: CollectionAdapter<Elem = <Self::Item as Try>::Output> + ~const TryCollectionStrategy<Self::MinLength, Self::MaxLength, C> + ?Sized,
-        Self::Item: ~const Try<Residual: ~const Residual<()> + ~const Residual<C, TryType: ~const Try> + ~const Destruct> + ~const Destruct
+        F: FnMut(Self::Item) -> B,
     {
-        FromBulk::<A>::try_from_bulk(self)
+        Map::new(self, f)
     }
 
-    fn collect_nearest(self) -> <BulkLength<Self> as Nearest>::NearestFrom<Self>
+    /// Creates a bulk which uses a closure to determine if an element
+    /// should be yielded.
+    ///
+    /// Given an element the closure must return `true` or `false`. The
+    /// returned bulk will yield only the elements for which the closure
+    /// returns `true`.
+    ///
+    /// The length of the returned bulk is bounded by the length of the original bulk,
+    /// so filtering an array still gives a bulk with a length known to be at most that of the array.
+    ///
+    /// Similar to [`Iterator::filter`].
+    ///
+    /// # Notes about length
+    ///
+    /// The exact length of the bulk can't be known without evaluating the predicate.
+    /// Finding it runs the predicate again on a clone of the bulk every time the length is needed,
+    /// so the bulk, its iterator and the predicate must be [`Clone`] for the result to be a [`Bulk`]. Otherwise, it can only be iterated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [0i32, 1, 2];
+    ///
+    /// let b: Vec<_> = a.into_bulk()
+    ///     .filter(|x| x.is_positive())
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [1, 2]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn filter<P>(self, predicate: P) -> Filter<Self, P>
     where
         Self: Sized,
-        BulkLength<Self>: ~const Nearest,
-        Self::Item: ~const Destruct
+        P: FnMut(&Self::Item) -> bool
     {
-        self.collect::<
-            <BulkLength<Self> as Nearest>::NearestFrom<Self>,
-            <BulkLength<Self> as Nearest>::NearestStrategyFrom<Self>
-        >()
+        Filter::new(self, predicate)
     }
 
-    fn try_collect_nearest(self) -> <<Self::Item as Try>::Residual as Residual<<BulkLength<Self> as Nearest>::TryNearestFrom<Self>>>::TryType
+    /// Creates a bulk that both filters and maps.
+    ///
+    /// The returned bulk yields only the `value`s for which the supplied
+    /// closure returns `Some(value)`.
+    ///
+    /// The length of the returned bulk is bounded by the length of the original bulk.
+    ///
+    /// Similar to [`Iterator::filter_map`].
+    ///
+    /// # Notes about length
+    ///
+    /// The exact length of the bulk can't be known without evaluating the closure.
+    /// Finding it runs the closure again on a clone of the bulk every time the length is needed,
+    /// so the bulk, its iterator and the closure must be [`Clone`] for the result to be a [`Bulk`]. Otherwise, it can only be iterated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = ["1", "two", "NaN", "four", "5"];
+    ///
+    /// let b: Vec<_> = a.into_bulk()
+    ///     .filter_map(|s| s.parse::<i32>().ok())
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [1, 5]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn filter_map<B, F>(self, f: F) -> FilterMap<Self, F>
     where
         Self: Sized,
-        BulkLength<Self>: ~const Nearest,
-        Self::Item: ~const Try<Output: ~const Destruct, Residual: ~const Residual<<BulkLength<Self> as Nearest>::TryNearestFrom<Self>> + ~const Residual<()> + ~const Destruct> + ~const Destruct
+        F: FnMut(Self::Item) -> Option<B>
     {
-        self.try_collect::<
-            <BulkLength<Self> as Nearest>::TryNearestFrom<Self>,
-            <BulkLength<Self> as Nearest>::TryNearestStrategyFrom<Self>
-        >()
+        FilterMap::new(self, f)
     }
 
-    /// Transforms a statically sized bulk into an array.
-    /// The bulk must implement [`StaticBulk`].
-    /// 
-    /// This is equivalent to [`collect()`](Bulk::collect), but the type does not need to be inferred.
-    /// For types other than arrays, use [`collect()`](Bulk::collect).
+    /// A bulk adapter which, like [`map`](Bulk::map), calls a closure on each element, but also
+    /// keeps an internal state that is passed to every call.
     ///
-    /// # Examples
+    /// `scan()` takes two arguments: an initial value which seeds the internal
+    /// state, and a closure with two arguments, the first being a mutable
+    /// reference to the internal state and the second a bulk element.
+    /// The closure can assign to the internal state to share state between
+    /// iterations, and its return value is yielded by the bulk.
     ///
-    /// Basic usage:
+    /// Unlike [`Iterator::scan`], the closure cannot end the bulk early, so the length of the bulk is preserved.
+    ///
+    /// Similar to [`Iterator::scan`].
+    ///
+    /// # Examples
     ///
     /// ```
     /// use bulks::*;
-    /// 
-    /// let a = [1, 2, 3];
     ///
-    /// let doubled = a.bulk()
-    ///     .map(|x| x * 2)
-    ///     .collect_array();
+    /// let a = [1, 2, 3, 4];
     ///
-    /// assert_eq!(doubled, [2, 4, 6]);
+    /// let prefix_sums: [_; _] = a.into_bulk()
+    ///     .scan(0, |acc, x| {
+    ///         *acc += x;
+    ///         *acc
+    ///     })
+    ///     .collect();
+    ///
+    /// assert_eq!(prefix_sums, [1, 3, 6, 10]);
     /// ```
-    /// 
-    /// Alternatively, [`collect()`](Bulk::collect) can be used, but this requires us to specify the return type.
+    ///
+    /// A first-order IIR filter:
     ///
     /// ```
     /// use bulks::*;
-    /// 
-    /// let a = [1, 2, 3];
     ///
-    /// let doubled: [i32; 3] = a.bulk()
-    ///     .map(|x| x * 2)
+    /// let x = [1.0, 0.0, 0.0, 0.0];
+    ///
+    /// let y: [f64; _] = x.into_bulk()
+    ///     .scan(0.0, |y, x| {
+    ///         *y = x + 0.5**y;
+    ///         *y
+    ///     })
     ///     .collect();
     ///
-    /// assert_eq!(doubled, [2, 4, 6]);
+    /// assert_eq!(y, [1.0, 0.5, 0.25, 0.125]);
     /// ```
-    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
-    fn collect_array(self) -> <Self as StaticBulk>::Array<<Self as IntoIterator>::Item>
+    #[inline]
+    #[track_caller]
+    fn scan<St, B, F>(self, initial_state: St, f: F) -> Scan<Self, St, F>
     where
-        Self: StaticBulk
+        Self: Sized,
+        F: FnMut(&mut St, Self::Item) -> B,
     {
-        util::collect_array_with!(|f| self.for_each(f); for Self)
+        Scan::new(self, initial_state, f)
     }
 
-    /// Fallibly transforms a statically sized bulk into an array, short circuiting if
-    /// a failure is encountered.
-    /// The bulk must implement [`StaticBulk`].
+    /// A bulk adapter that folds every element into an accumulator, like [`fold`](Bulk::fold), but yields
+    /// the accumulator after every step.
     ///
-    /// `try_collect_array()` is a variation of [`collect_array()`][`Bulk::collect_array`] that allows fallible
-    /// conversions during collection. Its main use case is simplifying conversions from
-    /// iterators yielding [`Option<T>`][`Option`] into `Option<Collection<T>>`, or similarly for other [`Try`]
-    /// types (e.g. [`Result`]).
+    /// This is an inclusive prefix sum, or more generally, an inclusive prefix scan with the operation `f`.
+    /// The length of the bulk is preserved.
     ///
-    /// Importantly, `try_collect()` doesn't require that the outer [`Try`] type also implements [`FromBulk`];
-    /// only the inner type produced on `Try::Output` must implement it. Concretely,
-    /// this means that collecting into `ControlFlow<_, Vec<i32>>` is valid because `Vec<i32>` implements
-    /// [`FromBulk`], even though [`ControlFlow`] doesn't.
-    /// 
-    /// This is equivalent to [`try_collect()`](Bulk::try_collect), but the type does not need to be inferred.
-    /// For types other than arrays, use [`try_collect()`](Bulk::try_collect).
-    /// 
-    /// Unlike with [`Iterator::try_collect`], the bulk is fully consumed even if it short-circuits.
-    /// A short-circuit will cause the rest of the elements of the bulk to be dropped.
+    /// See also [`accumulate_exclusive`](Bulk::accumulate_exclusive).
     ///
     /// # Examples
-    /// 
-    /// Successfully collecting a bulk of `Option<i32>` into `Option<[i32; _]>`:
-    /// ```
-    /// use bulks::*;
-    /// 
-    /// let u = [Some(1), Some(2), Some(3)];
-    /// let v = u.into_bulk().try_collect_array();
-    /// assert_eq!(v, Some([1, 2, 3]));
-    /// ```
     ///
-    /// Failing to collect in the same way:
-    /// ```
-    /// use bulks::*;
-    /// 
-    /// let u = [Some(1), Some(2), None, Some(3)];
-    /// let v = u.into_bulk().try_collect_array();
-    /// assert_eq!(v, None);
-    /// ```
-    /// 
-    /// Alternatively, [`try_collect()`](Bulk::try_collect) can be used, but this requires us to specify the return type.
     /// ```
     /// use bulks::*;
-    /// 
-    /// let u = [Some(1), Some(2), Some(3)];
-    /// let v: Option<[i32; 3]> = u.into_bulk().try_collect();
-    /// assert_eq!(v, Some([1, 2, 3]));
-    /// ```
+    ///
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// let sums: [_; _] = a.into_bulk()
+    ///     .accumulate(0, |acc, x| acc + x)
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, [1, 3, 6, 10]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn accumulate<B, F>(self, init: B, f: F) -> Accumulate<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        Accumulate::new(self, init, f)
+    }
+
+    /// A bulk adapter that folds every element into an accumulator, like [`fold`](Bulk::fold), but yields
+    /// the accumulator before every step, and finally the total.
+    ///
+    /// This is an exclusive prefix sum, or more generally, an exclusive prefix scan with the operation `f`.
+    /// The resulting bulk is one element longer than the original, and the first element is always `init`.
+    ///
+    /// See also [`accumulate`](Bulk::accumulate).
+    ///
+    /// # Examples
+    ///
+    /// Building an offset table:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let lengths = [3u32, 1, 4, 1, 5, 9, 2, 6];
+    ///
+    /// let offsets: [u32; 9] = lengths.into_bulk()
+    ///     .accumulate_exclusive(0, |acc, x| acc + x)
+    ///     .collect();
+    ///
+    /// assert_eq!(offsets, [0, 3, 4, 8, 9, 14, 23, 25, 31]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn accumulate_exclusive<B, F>(self, init: B, f: F) -> AccumulateExclusive<Self, B, F>
+    where
+        Self: Sized,
+        B: Clone,
+        F: FnMut(B, Self::Item) -> B,
+    {
+        AccumulateExclusive::new(self, init, f)
+    }
+
+    /// Creates a bulk which gives the current index together with its values.
+    ///
+    /// The bulk returned yields pairs `(i, val)`, where `i` is the
+    /// current index of iteration and `val` is its corresponding value.
+    /// 
+    /// Similar to [`Iterator::enumerate`].
+    ///
+    /// [`enumerate()`](Bulk::enumerate) keeps its count as a [`usize`]. If you want to count by a
+    /// different sized integer, use [`enumerate_from`](Bulk::enumerate_from) instead.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so enumerating more than
+    /// [`usize::MAX`] elements either produces the wrong result or panics. If
+    /// overflow checks are enabled, a panic is guaranteed.
+    ///
+    /// # Panics
+    ///
+    /// The returned bulk might panic if the to-be-returned index would
+    /// overflow a [`usize`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = ['a', 'b', 'c'];
+    ///
+    /// let b = a.into_bulk()
+    ///     .enumerate()
+    ///     .collect_array();
+    ///
+    /// assert_eq!(b, [(0, 'a'), (1, 'b'), (2, 'c')]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn enumerate(self) -> Enumerate<Self>
+    where
+        Self: Sized
+    {
+        Enumerate::new(self)
+    }
+
+    /// Creates a bulk which gives the current index counting from a given initial index together with its values.
+    ///
+    /// The bulk returned yields pairs `(i, val)`, where `i` is the
+    /// current index of iteration and `val` is its corresponding value.
+    /// 
+    /// This is similar to [`Bulk::enumerate`], except here a different type and initial value for counting can be used.
+    /// For counting an [`usize`] from 0 and up, [`Bulk::enumerate`] is a better alternative.
+    ///
+    /// # Overflow Behavior
+    ///
+    /// The method does no guarding against overflows, so enumerating more elements than supported values of `U`
+    /// either produces the wrong result or panics. If
+    /// overflow checks are enabled, a panic will happen depending how [`Step::forward`] is implemented for `U`.
+    ///
+    /// # Panics
+    ///
+    /// The returned bulk might panic if the to-be-returned index would
+    /// overflow.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = ['a', 'b', 'c'];
+    ///
+    /// let b = a.into_bulk()
+    ///     .enumerate_from(1)
+    ///     .collect_array();
+    ///
+    /// assert_eq!(b, [(1, 'a'), (2, 'b'), (3, 'c')]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn enumerate_from<U>(self, initial_count: U) -> EnumerateFrom<Self, U>
+    where
+        Self: Sized,
+        U: Step + Copy
+    {
+        EnumerateFrom::new(self, initial_count)
+    }
+
+    /// Creates a bulk that skips the first `n` elements.
+    /// 
+    /// Similar to [`Iterator::skip`].
+    ///
+    /// [`skip(n)`](Bulk::skip) skips elements until `n` elements are skipped or the end of the
+    /// bulk is reached (whichever happens first). The returned bulk will yield the remaining elements.
+    /// If the original bulk is too short, then the returned bulk is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let b: [_; _] = a.into_bulk().skip([(); 2]).collect();
+    /// let c: Vec<_> = a.into_bulk().skip(2).collect();
+    ///
+    /// assert_eq!(b, [3]);
+    /// assert_eq!(c, [3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn skip<L>(self, n: L) -> Skip<Self, L::Length<()>>
+    where
+        Self: Sized,
+        L: LengthValue
+    {
+        Skip::new(self, n)
+    }
+
+    /// Creates a bulk for the first `n` elements, or fewer
+    /// if the underlying bulk/iterator is shorter.
+    ///
+    /// [`take(n)`](Bulk::take) yields elements until `n` elements are yielded or the end of the
+    /// bulk is reached (whichever happens first).
+    /// The returned bulk is a prefix of length `n` if the original bulk/iterator
+    /// contains at least `n` elements, otherwise it contains all of the
+    /// (fewer than `n`) elements of the original bulk/iterator.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let b: Vec<_> = a.into_bulk().take([(); 2]).collect();
+    ///
+    /// assert_eq!(b, [1, 2]);
+    /// ```
+    ///
+    /// `take()` is often used with an infinite iterator, to make it finite:
+    ///
+    /// ```
+    /// let a: Vec<_> = (0..).take(3).collect();
+    ///
+    /// assert_eq!(a, [0, 1, 2])
+    /// ```
+    ///
+    /// If less than `n` elements are available,
+    /// [`take`](Bulk::take) will limit itself to the size of the underlying bulk/iterator:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let v = [1, 2];
+    /// let b: [_; _] = v.into_bulk().take([(); 5]).collect();
+    /// 
+    /// assert_eq!(b, [1, 2])
+    /// ```
+    #[doc(alias = "limit")]
+    #[inline]
+    #[track_caller]
+    fn take<L>(self, n: L) -> Take<Self, L::Length<()>>
+    where
+        Self: Sized,
+        L: LengthValue
+    {
+        Take::new(self, n)
+    }
+
+    /// Creates a bulk that skips the last `n` elements.
+    ///
+    /// This is the right-hand counterpart of [`skip`](Bulk::skip). Since the length of a bulk is always known,
+    /// this does not require the bulk to be double-ended. If the original bulk is too short, then the returned bulk is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let b: [_; _] = a.into_bulk().skip_last([(); 2]).collect();
+    /// let c: Vec<_> = a.into_bulk().skip_last(2).collect();
+    ///
+    /// assert_eq!(b, [1]);
+    /// assert_eq!(c, [1]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn skip_last<L>(self, n: L) -> SkipLast<Self, L::Length<()>>
+    where
+        Self: Sized,
+        L: LengthValue
+    {
+        SkipLast::new(self, n)
+    }
+
+    /// Creates a bulk for the last `n` elements, or fewer
+    /// if the underlying bulk is shorter.
+    ///
+    /// This is the right-hand counterpart of [`take`](Bulk::take). Since the length of a bulk is always known,
+    /// this does not require the bulk to be double-ended. The returned bulk is a suffix of length `n` if the original bulk
+    /// contains at least `n` elements, otherwise it contains all of the (fewer than `n`) elements of the original bulk.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let b: [_; _] = a.into_bulk().take_last([(); 2]).collect();
+    /// let c: Vec<_> = a.into_bulk().take_last(5).collect();
+    ///
+    /// assert_eq!(b, [2, 3]);
+    /// assert_eq!(c, [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn take_last<L>(self, n: L) -> TakeLast<Self, L::Length<()>>
+    where
+        Self: Sized,
+        L: LengthValue
+    {
+        TakeLast::new(self, n)
+    }
+
+    /// Creates a bulk that yields elements based on a predicate.
+    ///
+    /// `take_while()` takes a closure as an argument. It will call this
+    /// closure on each element of the bulk, and yield elements
+    /// while it returns `true`.
+    ///
+    /// After `false` is returned, the rest of the elements are ignored.
+    ///
+    /// The length of the returned bulk is bounded by the length of the original bulk.
+    ///
+    /// Similar to [`Iterator::take_while`].
+    ///
+    /// # Notes about length
+    ///
+    /// The exact length of the bulk can't be known without evaluating the predicate.
+    /// Finding it runs the predicate again on a clone of the bulk every time the length is needed,
+    /// so the bulk, its iterator and the predicate must be [`Clone`] for the result to be a [`Bulk`]. Otherwise, it can only be iterated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [-1i32, 0, 1];
+    ///
+    /// let b: Vec<_> = a.into_bulk()
+    ///     .take_while(|x| x.is_negative())
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [-1]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn take_while<P>(self, predicate: P) -> TakeWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool
+    {
+        TakeWhile::new(self, predicate)
+    }
+
+    /// Creates a bulk that [`skip`](Bulk::skip)s elements based on a predicate.
+    ///
+    /// `skip_while()` takes a closure as an argument. It will call this
+    /// closure on each element of the bulk, and ignore elements
+    /// until it returns `false`.
+    ///
+    /// After `false` is returned, the rest of the elements are yielded.
+    ///
+    /// The length of the returned bulk is bounded by the length of the original bulk.
+    ///
+    /// Similar to [`Iterator::skip_while`].
+    ///
+    /// # Notes about length
+    ///
+    /// The exact length of the bulk can't be known without evaluating the predicate.
+    /// Finding it runs the predicate again on a clone of the bulk every time the length is needed,
+    /// so the bulk, its iterator and the predicate must be [`Clone`] for the result to be a [`Bulk`]. Otherwise, it can only be iterated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [-1i32, 0, 1];
+    ///
+    /// let b: Vec<_> = a.into_bulk()
+    ///     .skip_while(|x| x.is_negative())
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [0, 1]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn skip_while<P>(self, predicate: P) -> SkipWhile<Self, P>
+    where
+        Self: Sized,
+        P: FnMut(&Self::Item) -> bool
+    {
+        SkipWhile::new(self, predicate)
+    }
+
+    /// Creates a bulk that both yields elements based on a predicate and maps.
+    ///
+    /// `map_while()` takes a closure as an argument. It will call this
+    /// closure on each element of the bulk, and yield elements
+    /// while it returns [`Some(_)`](Some).
+    ///
+    /// The length of the returned bulk is bounded by the length of the original bulk.
+    ///
+    /// Similar to [`Iterator::map_while`].
+    ///
+    /// # Notes about length
+    ///
+    /// The exact length of the bulk can't be known without evaluating the closure.
+    /// Finding it runs the closure again on a clone of the bulk every time the length is needed,
+    /// so the bulk, its iterator and the closure must be [`Clone`] for the result to be a [`Bulk`]. Otherwise, it can only be iterated.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [-1i32, 4, 0, 1];
+    ///
+    /// let b: Vec<_> = a.into_bulk()
+    ///     .map_while(|x| 16i32.checked_div(x))
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [-16, 4]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn map_while<B, F>(self, f: F) -> MapWhile<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> Option<B>
+    {
+        MapWhile::new(self, f)
+    }
+
+    /// Creates a bulk that works like map, but flattens nested structure.
+    ///
+    /// The [`map`](Bulk::map) adapter is very useful, but only when the closure
+    /// argument produces values. If it produces something iterable instead, there's
+    /// an extra layer of indirection. [`flat_map()`](Bulk::flat_map) will remove this extra layer
+    /// on its own.
+    /// 
+    /// Similar to [`Iterator::flat_map`].
+    ///
+    /// You can think of `flat_map(f)` as the semantic equivalent
+    /// of [`map`](Bulk::map)ping, and then [`flatten`](Bulk::flatten)ing as in `map(f).flatten()`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let words = [b"alpha", b"beta ", b"gamma"];
+    ///
+    /// let merged: String = words.into_bulk()
+    ///     .flat_map(|&s| s.into_bulk().map(|b| char::from(b)))
+    ///     .collect();
+    /// assert_eq!(merged, "alphabeta gamma");
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn flat_map<U, F>(self, f: F) -> FlatMap<Self, F>
+    where
+        Self: Sized,
+        U: IntoBulk<IntoBulk: StaticBulk>,
+        F: FnMut(Self::Item) -> U,
+    {
+        FlatMap::new(self, f)
+    }
+
+    /// Creates a bulk that flattens nested structure.
+    ///
+    /// This is useful when you have a bulk of bulk or a bulk of
+    /// things that can be turned into bulks and you want to remove one
+    /// level of indirection.
+    /// 
+    /// Similar to [`Iterator::flatten`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let data = [[1, 2, 3], [4, 5, 6]];
+    /// let flattened: [_; _] = data.into_bulk().flatten().collect();
+    /// assert_eq!(flattened, [1, 2, 3, 4, 5, 6]);
+    /// ```
+    ///
+    /// Mapping and then flattening:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let words = [b"alpha", b"beta ", b"gamma"];
+    ///
+    /// let merged: String = words.into_bulk()
+    ///     .map(|&s| s.into_bulk().map(|b| char::from(b)))
+    ///     .flatten()
+    ///     .collect();
+    /// assert_eq!(merged, "alphabeta gamma");
+    /// ```
+    ///
+    /// You can also rewrite this in terms of [`flat_map()`](Bulk::flat_map), which is preferable
+    /// in this case since it conveys intent more clearly:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let words = [b"alpha", b"beta ", b"gamma"];
+    ///
+    /// let merged: String = words.into_bulk()
+    ///     .flat_map(|&s| s.into_bulk().map(|b| char::from(b)))
+    ///     .collect();
+    /// assert_eq!(merged, "alphabeta gamma");
+    /// ```
+    ///
+    /// Flattening only removes one level of nesting at a time:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let d3 = [[[1, 2], [3, 4]], [[5, 6], [7, 8]]];
+    ///
+    /// let d2: [_; _] = d3.into_bulk().flatten().collect();
+    /// assert_eq!(d2, [[1, 2], [3, 4], [5, 6], [7, 8]]);
+    ///
+    /// let d1: [_; _] = d3.into_bulk().flatten().flatten().collect();
+    /// assert_eq!(d1, [1, 2, 3, 4, 5, 6, 7, 8]);
+    /// ```
+    ///
+    /// Here we see that [`flatten()`](Bulk::flatten) does not perform a "deep" flatten.
+    /// Instead, only one level of nesting is removed. That is, if you
+    /// [`flatten()`](Bulk::flatten) a three-dimensional array, the result will be
+    /// two-dimensional and not one-dimensional. To get a one-dimensional
+    /// structure, you have to [`flatten()`](Bulk::flatten) again.
+    #[inline]
+    #[track_caller]
+    fn flatten(self) -> Flatten<Self>
+    where
+        Self: Sized,
+        Self::Item: IntoBulk<IntoBulk: StaticBulk>,
+    {
+        Flatten::new(self)
+    }
+
+    /// Turns the rows of a bulk into columns.
+    ///
+    /// A bulk of `N` items, each of which can be converted into a bulk of `M` elements, becomes
+    /// a bulk of `M` arrays of `N` elements. Like [`flatten`](Bulk::flatten), this requires both the bulk
+    /// and its items to have a length known at compile-time.
+    ///
+    /// The bulk itself is consumed up front, while the items are consumed one column at a time.
+    /// This means a bulk of bulks can be zipped together column-wise, which is the reverse direction
+    /// of turning arrays of elements into bulks of arrays.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [[1, 2, 3], [4, 5, 6]];
+    ///
+    /// let t: [_; _] = a.into_bulk()
+    ///     .transpose()
+    ///     .collect();
+    ///
+    /// assert_eq!(t, [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    ///
+    /// Zipping bulks column-wise:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let rows = [
+    ///     [1, 2, 3].into_bulk().rev(),
+    ///     [4, 5, 6].into_bulk().rev()
+    /// ];
+    ///
+    /// let t: [_; _] = rows.into_bulk()
+    ///     .transpose()
+    ///     .collect();
+    ///
+    /// assert_eq!(t, [[3, 6], [2, 5], [1, 4]]);
+    /// ```
+    #[inline]
+    fn transpose<const N: usize, const M: usize>(self) -> Transpose<Self, N, M>
+    where
+        Self: Sized + StaticBulk<Array<()> = [(); N]>,
+        Self::Item: IntoBulk<IntoBulk: StaticBulk<Array<()> = [(); M]>>
+    {
+        Transpose::new(self)
+    }
+
+    /// Calls the given function `f` for each contiguous window of size `N` over
+    /// `self` and returns a bulk of the outputs of `f`. The windows during mapping will overlap.
+    /// 
+    /// Similar to [`Iterator::map_windows`].
+    ///
+    /// In the following example, the closure is called three times with the
+    /// arguments `&['a', 'b']`, `&['b', 'c']` and `&['c', 'd']` respectively.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let strings: [_; _] = b"abcd".bulk()
+    ///     .map(|&c| char::from(c))
+    ///     .map_windows(|[x, y]| format!("{}+{}", x, y))
+    ///     .collect();
+    ///
+    /// assert_eq!(strings, ["a+b", "b+c", "c+d"]);
+    /// ```
+    ///
+    /// Note that the const parameter `N` is usually inferred by the
+    /// destructured argument in the closure.
+    ///
+    /// The returned bulk yields 𝑘 − `N` + 1 items (where 𝑘 is the number of
+    /// items yielded by `self`). If 𝑘 is less than `N`, this method yields an
+    /// empty bulk.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// ```should_panic
+    /// use bulks::*;
+    ///
+    /// let bulk = [0].into_bulk().map_windows(|&[]| ());
+    /// ```
+    ///
+    /// # Examples
+    ///
+    /// Building the sums of neighboring numbers.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let w: [_; _] = [1, 3, 8, 1].bulk()
+    ///     .map_windows(|&[a, b]| a + b)
+    ///     .collect();
+    /// 
+    /// assert_eq!(w, [1 + 3, 3 + 8, 8 + 1]);
+    /// ```
+    ///
+    /// Since the elements in the following example implement [`Copy`], we can
+    /// just copy the array and get a bulk of the windows.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let w: [[_; _]; _] = b"ferris".bulk()
+    ///     .map_windows(|w: &[_; 3]| w.bulk()
+    ///         .copied()
+    ///         .copied()
+    ///         .collect())
+    ///     .collect();
+    /// 
+    /// assert_eq!(w, [[b'f', b'e', b'r'], [b'e', b'r', b'r'], [b'r', b'r', b'i'], [b'r', b'i', b's']]);
+    /// ```
+    ///
+    /// You can also use this function to check the sortedness of a bulk.
+    /// For the simple case, rather use [`Bulk::is_sorted`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let w: [_; _] = [0.5, 1.0, 3.5, 3.0, 8.5, 8.5, f32::NAN].bulk()
+    ///     .map_windows(|[a, b]| a <= b)
+    ///     .collect();
+    /// 
+    /// assert_eq!(w, [true, true, false, true, true, false]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn map_windows<F, R, const N: usize>(self, f: F) -> MapWindows<Self, F, N>
+    where
+        Self: Sized,
+        F: FnMut(&[Self::Item; N]) -> R,
+    {
+        MapWindows::new(self, f)
+    }
+
+    /// Calls the given function `f` for every `H`-th contiguous window of size `N` over
+    /// `self` and returns a bulk of the outputs of `f`.
+    ///
+    /// Like [`map_windows`](Bulk::map_windows), but the start of each window is `H` elements after the start of the previous one.
+    /// The windows overlap if `H` is less than `N`, and elements are skipped between windows if `H` is greater than `N`.
+    /// With `H` equal to `1`, this is the same as [`map_windows`](Bulk::map_windows).
+    ///
+    /// The length of the resulting bulk is `(len - N)/H + 1`, or zero if the bulk is shorter than `N`.
+    /// If the length of the bulk is known at compile-time, so is the resulting length.
+    ///
+    /// When iterating from the back, the windows are the same as when iterating from the front.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` or `H` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Frames with 50% overlap:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let signal = [1, 2, 3, 4, 5, 6, 7, 8];
+    ///
+    /// let frames: [_; _] = signal.into_bulk()
+    ///     .map_windows_step::<_, _, 4, 2>(|&frame| frame)
+    ///     .collect();
+    ///
+    /// assert_eq!(frames, [[1, 2, 3, 4], [3, 4, 5, 6], [5, 6, 7, 8]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn map_windows_step<F, R, const N: usize, const H: usize>(self, f: F) -> MapWindowsStep<Self, F, N, H>
+    where
+        Self: Sized,
+        F: FnMut(&[Self::Item; N]) -> R,
+    {
+        MapWindowsStep::new(self, f)
+    }
+
+    /// Does something with each element of a bulk, passing the value on.
+    ///
+    /// When using bulks, you'll often chain several of them together.
+    /// While working on such code, you might want to check out what's
+    /// happening at various parts in the pipeline. To do that, insert
+    /// a call to [`inspect()`](Bulk::inspect).
+    /// 
+    /// Similar to [`Iterator::inspect`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 4, 2, 3];
+    ///
+    /// // this iterator sequence is complex.
+    /// let sum = a.bulk()
+    ///     .cloned()
+    ///     .map(|x| if x % 2 == 0 {Some(x)} else {None})
+    ///     .fold(0, |sum, i| sum + i.unwrap_or(0));
+    ///
+    /// println!("{sum}");
+    ///
+    /// // let's add some inspect() calls to investigate what's happening
+    /// let sum = a.bulk()
+    ///     .cloned()
+    ///     .inspect(|x| println!("about to filter: {x}"))
+    ///     .map(|x| if x % 2 == 0 {Some(x)} else {None})
+    ///     .inspect(|x| if let Some(x) = x {println!("made it through filter: {x}")})
+    ///     .fold(0, |sum, i| sum + i.unwrap_or(0));
+    ///
+    /// println!("{sum}");
+    /// ```
+    ///
+    /// This will print:
+    ///
+    /// ```text
+    /// 6
+    /// about to filter: 1
+    /// about to filter: 4
+    /// made it through filter: 4
+    /// about to filter: 2
+    /// made it through filter: 2
+    /// about to filter: 3
+    /// 6
+    /// ```
+    ///
+    /// Logging errors before discarding them:
+    ///
+    /// ```
+    /// let lines = ["1", "2", "a"];
+    ///
+    /// let sum: i32 = lines
+    ///     .iter()
+    ///     .map(|line| line.parse::<i32>())
+    ///     .inspect(|num| {
+    ///         if let Err(ref e) = *num {
+    ///             println!("Parsing error: {e}");
+    ///         }
+    ///     })
+    ///     .filter_map(Result::ok)
+    ///     .sum();
+    ///
+    /// println!("Sum: {sum}");
+    /// ```
+    ///
+    /// This will print:
+    ///
+    /// ```text
+    /// Parsing error: invalid digit found in string
+    /// Sum: 3
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn inspect<F>(self, f: F) -> Inspect<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&Self::Item),
+    {
+        Inspect::new(self, f)
+    }
+
+    /// Mutates with each element of a bulk, passing the value on.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 4, 2, 3];
+    ///
+    /// // this iterator sequence is complex.
+    /// let b: [_; _] = a.into_bulk()
+    ///     .mutate(|x| *x += 1)
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [2, 5, 3, 4]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn mutate<F>(self, f: F) -> Mutate<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(&mut Self::Item),
+    {
+        Mutate::new(self, f)
+    }
+
+    /// Transforms a bulk into a collection.
+    ///
+    /// [`collect()`](Bulk::collect) can take anything bulkable, and turn it into a relevant
+    /// collection.
+    /// 
+    /// Similar to [`Iterator::collect`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let doubled: [i32; 3] = a.bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    ///
+    /// Note that we needed the `: [i32; 3]` on the left-hand side. This is because
+    /// we could collect into, for example, a [`VecDeque<T>`](std::collections::VecDeque) instead:
+    ///
+    /// ```
+    /// use std::collections::VecDeque;
+    /// 
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let doubled: VecDeque<i32> = a.bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(doubled[0], 2);
+    /// assert_eq!(doubled[1], 4);
+    /// assert_eq!(doubled[2], 6);
+    /// ```
+    ///
+    /// Using the 'turbofish' instead of annotating `doubled`:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let doubled = a.bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect::<[i32; 3], _>();
+    ///
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    ///
+    /// Because `collect()` only cares about what you're collecting into, you can
+    /// still use a partial type hint, `_`, with the turbofish:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let doubled: [_; _] = a.bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    ///
+    /// Using `collect()` to make a [`String`](std::string::String):
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let chars = ['g', 'd', 'k', 'k', 'n'];
+    ///
+    /// let hello: String = chars.bulk()
+    ///     .copied()
+    ///     .map(|x| x as u8)
+    ///     .map(|x| (x + 1) as char)
+    ///     .collect();
+    ///
+    /// assert_eq!(hello, "hello");
+    /// ```
+    ///
+    /// If you have a list of [`Result<T, E>`][`Result`]s, you can use `collect()` to
+    /// see if any of them failed:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let results = [Ok(1), Err("nope"), Ok(3), Err("bad")];
+    ///
+    /// let result: Result<[_; _], &str> = results.into_bulk().collect();
+    ///
+    /// // gives us the first error
+    /// assert_eq!(result, Err("nope"));
+    ///
+    /// let results = [Ok(1), Ok(3)];
+    ///
+    /// let result: Result<[_; _], &str> = results.into_bulk().collect();
+    ///
+    /// // gives us the list of answers
+    /// assert_eq!(result, Ok([1, 3]));
+    /// ```
+    #[inline]
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn collect<C, A>(self) -> C
+    where
+        Self: Sized,
+        C: ~const FromBulk<A>,
+        A: CollectionAdapter<Elem = Self::Item> + ~const CollectionStrategy<Self::MinLength, Self::MaxLength, C> + ?Sized
+    {
+        FromBulk::from_bulk(self)
+    }
+
+    /// Fallibly transforms a bulk into a collection, short circuiting if
+    /// a failure is encountered.
+    ///
+    /// `try_collect()` is a variation of [`collect()`][`Bulk::collect`] that allows fallible
+    /// conversions during collection. Its main use case is simplifying conversions from
+    /// iterators yielding [`Option<T>`][`Option`] into `Option<Collection<T>>`, or similarly for other [`Try`]
+    /// types (e.g. [`Result`]).
+    ///
+    /// Importantly, `try_collect()` doesn't require that the outer [`Try`] type also implements [`FromBulk`];
+    /// only the inner type produced on `Try::Output` must implement it. Concretely,
+    /// this means that collecting into `ControlFlow<_, Vec<i32>>` is valid because `Vec<i32>` implements
+    /// [`FromBulk`], even though [`ControlFlow`] doesn't.
+    /// 
+    /// Unlike with [`Iterator::try_collect`], the bulk is fully consumed even if it short-circuits.
+    /// A short-circuit will cause the rest of the elements of the bulk to be dropped.
+    ///
+    /// # Examples
+    /// 
+    /// Successfully collecting a bulk of `Option<i32>` into `Option<[i32; _]>`:
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let u = [Some(1), Some(2), Some(3)];
+    /// 
+    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
+    /// 
+    /// assert_eq!(v, Some([1, 2, 3]));
+    /// ```
+    ///
+    /// Failing to collect in the same way:
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let u = [Some(1), Some(2), None, Some(3)];
+    /// 
+    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
+    /// 
+    /// assert_eq!(v, None);
+    /// ```
+    ///
+    /// A similar example, but with `Result`:
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let u: [Result<i32, ()>; _] = [Ok(1), Ok(2), Ok(3)];
+    /// 
+    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
+    /// 
+    /// assert_eq!(v, Ok([1, 2, 3]));
+    ///
+    /// let u = [Ok(1), Ok(2), Err(()), Ok(3)];
+    /// 
+    /// let v = u.into_bulk().try_collect::<[i32; _], _>();
+    /// 
+    /// assert_eq!(v, Err(()));
+    /// ```
+    ///
+    /// Finally, even [`ControlFlow`] works, despite the fact that it
+    /// doesn't implement [`FromBulk`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// use core::ops::ControlFlow::{Break, Continue};
+    ///
+    /// let u = [Continue(1), Continue(2), Break(3), Continue(4), Continue(5)];
+    /// 
+    /// let v = u.into_bulk().try_collect::<[_; _], _>();
+    /// 
+    /// assert_eq!(v, Break(3));
+    ///
+    /// let v = u.into_bulk().take([(); 2])
+    ///     .chain(u.into_bulk().skip([(); 3]))
+    ///     .try_collect::<[_; _], _>();
+    /// 
+    /// assert_eq!(v, Continue([1, 2, 4, 5]));
+    /// ```
+    #[inline]
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn try_collect<C, A>(self) -> <<Self::Item as Try>::Residual as Residual<C>>::TryType
+    where
+        Self: Sized,
+        C: ~const FromBulk<A>,
+        A: CollectionAdapter<Elem = <Self::Item as Try>::Output> + ~const TryCollectionStrategy<Self::MinLength, Self::MaxLength, C> + ?Sized,
+        Self::Item: ~const Try<Residual: ~const Residual<()> + ~const Residual<C, TryType: ~const Try> + ~const Destruct> + ~const Destruct
+    {
+        FromBulk::<A>::try_from_bulk(self)
+    }
+
+    /// Collects the bulk into the nearest collection that can hold it.
+    ///
+    /// Bulks with a length known at compile-time collect into an array, and bulks with a length
+    /// bounded by `N` collect into a [`BoundedArray<T, N>`](crate::BoundedArray). Neither of these allocate.
+    /// Any other bulk collects into a [`Vec`](std::vec::Vec), which requires the `alloc` feature.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3, 4, 5, 6];
+    ///
+    /// let b = a.into_bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect_nearest();
+    ///
+    /// assert_eq!(b, [2, 4, 6, 8, 10, 12]);
+    ///
+    /// let c = a.into_bulk()
+    ///     .filter(|x| x % 3 == 0)
+    ///     .collect_nearest();
+    ///
+    /// assert_eq!(c.capacity(), 6);
+    /// assert_eq!(c.as_slice(), [3, 6]);
+    /// ```
+    fn collect_nearest(self) -> <NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::NearestFrom<Self>
+    where
+        Self: Sized + CollectNearest,
+        NearestLength<Self>: ~const Nearest<Self::MaxLength, Self::MinLength>,
+        Self::Item: ~const Destruct
+    {
+        self.collect::<
+            <NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::NearestFrom<Self>,
+            <NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::NearestStrategyFrom<Self>
+        >()
+    }
+
+    #[allow(clippy::type_complexity)]
+    fn try_collect_nearest(self) -> <<Self::Item as Try>::Residual as Residual<<NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::TryNearestFrom<Self>>>::TryType
+    where
+        Self: Sized + CollectNearest,
+        NearestLength<Self>: ~const Nearest<Self::MaxLength, Self::MinLength>,
+        Self::Item: ~const Try<Output: ~const Destruct, Residual: ~const Residual<<NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::TryNearestFrom<Self>> + ~const Residual<()> + ~const Destruct> + ~const Destruct
+    {
+        self.try_collect::<
+            <NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::TryNearestFrom<Self>,
+            <NearestLength<Self> as Nearest<Self::MaxLength, Self::MinLength>>::TryNearestStrategyFrom<Self>
+        >()
+    }
+
+    /// Transforms a statically sized bulk into an array.
+    /// The bulk must implement [`StaticBulk`].
+    /// 
+    /// This is equivalent to [`collect()`](Bulk::collect), but the type does not need to be inferred.
+    /// For types other than arrays, use [`collect()`](Bulk::collect).
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let doubled = a.bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect_array();
+    ///
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    /// 
+    /// Alternatively, [`collect()`](Bulk::collect) can be used, but this requires us to specify the return type.
+    ///
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let a = [1, 2, 3];
+    ///
+    /// let doubled: [i32; 3] = a.bulk()
+    ///     .map(|x| x * 2)
+    ///     .collect();
+    ///
+    /// assert_eq!(doubled, [2, 4, 6]);
+    /// ```
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn collect_array(self) -> <Self as StaticBulk>::Array<<Self as IntoIterator>::Item>
+    where
+        Self: StaticBulk
+    {
+        util::collect_array_with!(|f| self.for_each(f); for Self)
+    }
+
+    /// Fallibly transforms a statically sized bulk into an array, short circuiting if
+    /// a failure is encountered.
+    /// The bulk must implement [`StaticBulk`].
+    ///
+    /// `try_collect_array()` is a variation of [`collect_array()`][`Bulk::collect_array`] that allows fallible
+    /// conversions during collection. Its main use case is simplifying conversions from
+    /// iterators yielding [`Option<T>`][`Option`] into `Option<Collection<T>>`, or similarly for other [`Try`]
+    /// types (e.g. [`Result`]).
+    ///
+    /// Importantly, `try_collect()` doesn't require that the outer [`Try`] type also implements [`FromBulk`];
+    /// only the inner type produced on `Try::Output` must implement it. Concretely,
+    /// this means that collecting into `ControlFlow<_, Vec<i32>>` is valid because `Vec<i32>` implements
+    /// [`FromBulk`], even though [`ControlFlow`] doesn't.
+    /// 
+    /// This is equivalent to [`try_collect()`](Bulk::try_collect), but the type does not need to be inferred.
+    /// For types other than arrays, use [`try_collect()`](Bulk::try_collect).
+    /// 
+    /// Unlike with [`Iterator::try_collect`], the bulk is fully consumed even if it short-circuits.
+    /// A short-circuit will cause the rest of the elements of the bulk to be dropped.
+    ///
+    /// # Examples
+    /// 
+    /// Successfully collecting a bulk of `Option<i32>` into `Option<[i32; _]>`:
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let u = [Some(1), Some(2), Some(3)];
+    /// let v = u.into_bulk().try_collect_array();
+    /// assert_eq!(v, Some([1, 2, 3]));
+    /// ```
+    ///
+    /// Failing to collect in the same way:
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let u = [Some(1), Some(2), None, Some(3)];
+    /// let v = u.into_bulk().try_collect_array();
+    /// assert_eq!(v, None);
+    /// ```
+    /// 
+    /// Alternatively, [`try_collect()`](Bulk::try_collect) can be used, but this requires us to specify the return type.
+    /// ```
+    /// use bulks::*;
+    /// 
+    /// let u = [Some(1), Some(2), Some(3)];
+    /// let v: Option<[i32; 3]> = u.into_bulk().try_collect();
+    /// assert_eq!(v, Some([1, 2, 3]));
+    /// ```
     ///
     /// A similar example, but with `Result`:
     /// ```
     /// use bulks::*;
     /// 
-    /// let u: [Result<i32, ()>; _] = [Ok(1), Ok(2), Ok(3)];
-    /// let v = u.into_bulk().try_collect_array();
-    /// assert_eq!(v, Ok([1, 2, 3]));
+    /// let u: [Result<i32, ()>; _] = [Ok(1), Ok(2), Ok(3)];
+    /// let v = u.into_bulk().try_collect_array();
+    /// assert_eq!(v, Ok([1, 2, 3]));
+    ///
+    /// let u = [Ok(1), Ok(2), Err(()), Ok(3)];
+    /// let v = u.into_bulk().try_collect_array();
+    /// assert_eq!(v, Err(()));
+    /// ```
+    ///
+    /// Finally, even [`ControlFlow`] works, despite the fact that it
+    /// doesn't implement [`FromBulk`].
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use core::ops::ControlFlow::{Break, Continue};
+    /// 
+    /// use bulks::*;
+    ///
+    /// let u = [Continue(1), Continue(2), Break(3), Continue(4), Continue(5)];
+    /// 
+    /// let v = u.into_bulk().try_collect_array();
+    /// assert_eq!(v, Break(3));
+    ///
+    /// let v = u.into_bulk().take([(); 2])
+    ///     .chain(u.into_bulk().skip([(); 3]))
+    ///     .try_collect_array();
+    /// assert_eq!(v, Continue([1, 2, 4, 5]));
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn try_collect_array(self) -> <<Self::Item as Try>::Residual as Residual<Self::Array<<Self::Item as Try>::Output>>>::TryType
+    where
+        Self: StaticBulk<Item: ~const Destruct + ~const Try<Residual: Residual<(), TryType: ~const Try> + Residual<Self::Array<<Self::Item as Try>::Output>, TryType: ~const Try> + ~const Destruct, Output: ~const Destruct>> + ~const Bulk
+    {
+        Try::from_output(util::try_collect_array_with!(|pusher| self.try_for_each(pusher)?; for Self))
+    }
+
+    /// Converts a bulk of pairs into a pair of collections.
+    ///
+    /// `unzip()` consumes an entire bulk of pairs, producing two
+    /// collections: one from the left elements of the pairs, and one
+    /// from the right elements. The bulk is only consumed once.
+    ///
+    /// If the bulk is a [`StaticBulk`], both sides can be collected into arrays.
+    ///
+    /// For tuples with more than two elements, see [`multiunzip`](Bulk::multiunzip).
+    ///
+    /// Similar to [`Iterator::unzip`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [(1, 2), (3, 4), (5, 6)];
+    ///
+    /// let (left, right): ([_; _], [_; _]) = a.into_bulk().unzip();
+    ///
+    /// assert_eq!(left, [1, 3, 5]);
+    /// assert_eq!(right, [2, 4, 6]);
+    /// ```
+    ///
+    /// The two sides may be collected into different kinds of collections:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [(1, 'a'), (2, 'b'), (3, 'c')];
+    ///
+    /// let (numbers, letters) = a.into_bulk().unzip::<[_; _], String, _, _>();
+    ///
+    /// assert_eq!(numbers, [1, 2, 3]);
+    /// assert_eq!(letters, "abc");
+    /// ```
+    #[inline]
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn unzip<CA, CB, AA, AB>(self) -> (CA, CB)
+    where
+        Self: Sized + ~const UnzipBulk<(CA, CB), (PhantomData<AA>, PhantomData<AB>)>,
+        AA: ?Sized,
+        AB: ?Sized
+    {
+        UnzipBulk::unzip(self)
+    }
+
+    /// Converts a bulk of tuples into a tuple of collections.
+    ///
+    /// This is the variadic form of [`unzip`](Bulk::unzip), and works with tuples of up to eight elements.
+    /// The bulk is only consumed once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [(1, 'a', 1.0), (2, 'b', 2.0), (3, 'c', 3.0)];
+    ///
+    /// let (numbers, letters, floats): ([_; _], [_; _], [_; _]) = a.into_bulk().multiunzip();
+    ///
+    /// assert_eq!(numbers, [1, 2, 3]);
+    /// assert_eq!(letters, ['a', 'b', 'c']);
+    /// assert_eq!(floats, [1.0, 2.0, 3.0]);
+    /// ```
+    #[inline]
+    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
+    fn multiunzip<C, A>(self) -> C
+    where
+        Self: Sized + ~const UnzipBulk<C, A>
+    {
+        UnzipBulk::unzip(self)
+    }
+
+    /// Resizes a bulk, padding it with copies of a given value of `element` if too short, or truncating it if too long.
+    /// The resuling bulk will have an exact length given by `n`.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// 
+    /// use bulks::*;
+    ///
+    /// let u = [1, 3, 3, 7];
+    /// 
+    /// let v = u.into_bulk()
+    ///     .resize([(); 6], 9)
+    ///     .collect_array();
+    /// assert_eq!(v, [1, 3, 3, 7, 9, 9]);
+    /// ```
+    fn resize<N>(self, n: N, element: Self::Item) -> Resize<Self, N::Length<()>>
+    where
+        Self: Sized,
+        Self::Item: Copy,
+        N: LengthValue
+    {
+        Resize::new(self, n, element)
+    }
+
+    /// Resizes a bulk, padding it with the output of `padder` if too short, or truncating it if too long.
+    /// The resuling bulk will have an exact length given by `n`.
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// 
+    /// use bulks::*;
+    ///
+    /// let u = [1, 3, 3, 7];
+    /// 
+    /// let v = u.into_bulk()
+    ///     .resize_with([(); 6], || 9)
+    ///     .collect_array();
+    /// assert_eq!(v, [1, 3, 3, 7, 9, 9]);
+    /// ```
+    fn resize_with<F, N>(self, n: N, padder: F) -> ResizeWith<Self, F, N::Length<()>>
+    where
+        Self: Sized,
+        N: LengthValue,
+        F: FnMut() -> Self::Item
+    {
+        ResizeWith::new(self, n, padder)
+    }
+
+    /// Reverses a bulks's direction.
+    ///
+    /// Usually, bulks span from left to right. After using `rev()`,
+    /// a bulk will instead span from right to left.
+    /// 
+    /// Similar to [`Iterator::rev`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let b: [_; _] = a.into_bulk().rev().collect();
+    ///
+    /// assert_eq!(b, [3, 2, 1]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    #[doc(alias = "reverse")]
+    fn rev(self) -> Rev<Self>
+    where
+        Self: Sized,
+        Self: DoubleEndedBulk
+    {
+        Rev::new(self)
+    }
+
+    /// Creates a bulk which copies all of its elements.
+    ///
+    /// This is useful when you have a bulk of `&T`, but you need a
+    /// bulk of `T`.
+    /// 
+    /// Similar to [`Iterator::copied`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let v_copied: [_; _] = a.bulk().copied().collect();
+    ///
+    /// // copied is the same as .map(|&x| x)
+    /// let v_map: [_; _] = a.bulk().map(|&x| x).collect();
+    ///
+    /// assert_eq!(v_copied, [1, 2, 3]);
+    /// assert_eq!(v_map, [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn copied<'a, T>(self) -> Copied<Self>
+    where
+        T: Copy + 'a,
+        Self: Sized + ~const Bulk<Item = &'a T>,
+    {
+        Copied::new(self)
+    }
+
+    /// Creates a bulk which [`clone`](Clone::clone)s all of its elements.
+    ///
+    /// This is useful when you have a bulk of `&T`, but you need a
+    /// bulk of `T`.
+    ///
+    /// There is no guarantee whatsoever about the `clone` method actually
+    /// being called *or* optimized away. So code should not depend on
+    /// either.
+    /// 
+    /// Similar to [`Iterator::cloned`].
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3];
+    ///
+    /// let v_cloned: [_; _] = a.bulk().cloned().collect();
+    ///
+    /// // cloned is the same as .map(|&x| x), for integers
+    /// let v_map: [_; _] = a.bulk().map(|&x| x).collect();
+    ///
+    /// assert_eq!(v_cloned, [1, 2, 3]);
+    /// assert_eq!(v_map, [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn cloned<'a, T>(self) -> Cloned<Self>
+    where
+        T: Clone + 'a,
+        Self: Sized + ~const Bulk<Item = &'a T>,
+    {
+        Cloned::new(self)
+    }
+
+    /// Returns a bulk of `N` elements of the bulk at a time.
+    ///
+    /// The chunks do not overlap. If `N` does not divide the length of the
+    /// bulk, then the last up to `N-1` elements will be omitted or the remainder
+    /// can then be retrieved from [`.into_remainder()`][crate::ArrayChunks::into_remainder]
+    /// or [`.collect_with_remainder()`][crate::ArrayChunks::collect_with_remainder]
+    /// 
+    /// Similar to [`Iterator::array_chunks`].
     ///
-    /// let u = [Ok(1), Ok(2), Err(()), Ok(3)];
-    /// let v = u.into_bulk().try_collect_array();
-    /// assert_eq!(v, Err(()));
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// Basic usage:
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let bulk = b"lorem".bulk()
+    ///     .copied()
+    ///     .array_chunks();
+    /// 
+    /// let (c, r) = bulk.collect_with_remainder::<[_; _], _>();
+    /// 
+    /// let r: Vec<_> = r.collect();
+    /// 
+    /// assert_eq!(c, [[b'l', b'o'], [b'r', b'e']]);
+    /// assert_eq!(r, [b'm']);
+    /// ```
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let data = [1, 1, 2, -2, 6, 0, 3, 1];
+    /// //          ^-----^  ^------^
+    /// for [x, y, z] in data.bulk().array_chunks()
+    /// {
+    ///     assert_eq!(x + y + z, 4);
+    /// }
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    where
+        Self: Sized,
+    {
+        ArrayChunks::new(self)
+    }
+
+    /// Splits the bulk into a bulk of `N` elements at a time, and the remaining elements.
+    ///
+    /// Unlike [`array_chunks`](Bulk::array_chunks), nothing is dropped. The first bulk yields the
+    /// non-overlapping chunks of exactly `N` elements, while the second bulk holds the last `len % N` elements
+    /// that did not fit into a chunk.
+    ///
+    /// This is done by splitting the bulk at `len - len % N`, so if the length of the bulk is known at compile-time,
+    /// so are the lengths of both the chunks and the remainder.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let (chunks, remainder) = b"lorem".bulk()
+    ///     .copied()
+    ///     .array_chunks_exact::<2>();
+    ///
+    /// let c: [_; _] = chunks.collect();
+    /// let r: [_; _] = remainder.collect();
+    ///
+    /// assert_eq!(c, [[b'l', b'o'], [b'r', b'e']]);
+    /// assert_eq!(r, [b'm']);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    #[track_caller]
+    fn array_chunks_exact<const N: usize>(self) -> (
+        ArrayChunks<<Self as SplitBulk<ChunksExactSplit<Self, N>>>::Left, N>,
+        <Self as SplitBulk<ChunksExactSplit<Self, N>>>::Right
+    )
+    where
+        Self: ~const SplitBulk<ChunksExactSplit<Self, N>> + Sized
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        let l = self.length();
+        let (left, right) = self.rsplit_at(length::value::rem(l, [(); N]));
+        (ArrayChunks::new(left), right)
+    }
+
+    /// Returns a bulk of `N` elements of the bulk at a time, aligned to the end of the bulk.
+    ///
+    /// The chunks do not overlap. If `N` does not divide the length of the bulk, then the first
+    /// up to `N-1` elements will be omitted. Use [`rarray_chunks_exact`](Bulk::rarray_chunks_exact) to keep them,
+    /// or [`.rev().for_each_with_remainder()`](crate::Rev::for_each_with_remainder) to process the chunks from the end
+    /// and get the leading elements back afterwards.
+    ///
+    /// This is useful for grouping digits of big-endian numbers, or processing blocks in reverse.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let digits = [1, 2, 3, 4, 5, 6, 7];
+    ///
+    /// let groups: [_; _] = digits.into_bulk()
+    ///     .rarray_chunks::<3>()
+    ///     .collect();
+    ///
+    /// assert_eq!(groups, [[2, 3, 4], [5, 6, 7]]);
+    /// ```
+    #[inline]
+    #[track_caller]
+    fn rarray_chunks<const N: usize>(self) -> RArrayChunks<Self, N>
+    where
+        Self: Sized
+    {
+        RArrayChunks::new(self)
+    }
+
+    /// Splits the bulk into the leading elements, and a bulk of `N` elements at a time aligned to the end of the bulk.
+    ///
+    /// This is the right-aligned counterpart of [`array_chunks_exact`](Bulk::array_chunks_exact). The first bulk holds
+    /// the first `len % N` elements that did not fit into a chunk, while the second bulk yields the non-overlapping chunks.
+    ///
+    /// This is done by splitting the bulk at `len % N`, so if the length of the bulk is known at compile-time,
+    /// so are the lengths of both the remainder and the chunks.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let (head, groups) = [1, 2, 3, 4, 5, 6, 7].into_bulk()
+    ///     .rarray_chunks_exact::<3>();
+    ///
+    /// let head: [_; _] = head.collect();
+    /// let groups: [_; _] = groups.collect();
+    ///
+    /// assert_eq!(head, [1]);
+    /// assert_eq!(groups, [[2, 3, 4], [5, 6, 7]]);
+    /// ```
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    #[track_caller]
+    fn rarray_chunks_exact<const N: usize>(self) -> (
+        <Self as SplitBulk<length::value::Rem<Value<BulkLength<Self>>, [(); N]>>>::Left,
+        ArrayChunks<<Self as SplitBulk<length::value::Rem<Value<BulkLength<Self>>, [(); N]>>>::Right, N>
+    )
+    where
+        Self: ~const SplitBulk<length::value::Rem<Value<BulkLength<Self>>, [(); N]>> + Sized
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        let l = self.length();
+        let (left, right) = SplitBulk::split_at(self, length::value::rem(l, [(); N]));
+        (left, ArrayChunks::new(right))
+    }
+
+    /// Splits a bulk in two at a specified index.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let a = b"leftright";
+    /// 
+    /// let (a1, a2) = a.bulk()
+    ///     .copied()
+    ///     .split_at([(); 4]);
+    /// 
+    /// let left: [_; _] = a1.collect();
+    /// let right: [_; _] = a2.collect();
+    /// 
+    /// assert_eq!(&left, b"left");
+    /// assert_eq!(&right, b"right");
+    /// ```
+    #[track_caller]
+    fn split_at<L>(self, n: L) -> (Self::Left, Self::Right)
+    where
+        Self: ~const SplitBulk<L> + Sized,
+        L: LengthValue
+    {
+        SplitBulk::split_at(self, n)
+    }
+
+    /// Splits a bulk in two at a specified reversed index.
+    /// 
+    /// # Example
+    /// 
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    /// 
+    /// let a = b"leftright";
+    /// 
+    /// let (a1, a2) = a.bulk()
+    ///     .copied()
+    ///     .rsplit_at([(); 5]);
+    /// 
+    /// let left: [_; _] = a1.collect();
+    /// let right: [_; _] = a2.collect();
+    /// 
+    /// assert_eq!(&left, b"left");
+    /// assert_eq!(&right, b"right");
+    /// ```
+    #[track_caller]
+    fn rsplit_at<L>(self, n: L) -> (Self::Left, Self::Right)
+    where
+        Self: ~const SplitBulk<length::value::SaturatingSub<<BulkLength<Self> as Length>::Value, L>> + Sized,
+        L: LengthValue
+    {
+        let l = self.length();
+        SplitBulk::split_at(self, length::value::saturating_sub(l, n))
+    }
+
+    /// Splits a bulk into `K` consecutive parts of equal length.
+    ///
+    /// Each part has a length of `len / K`, so if the length of the bulk is known at compile-time, so are the lengths of the parts.
+    /// The bulk is split by repeatedly splitting off the first part with [`split_at`](Bulk::split_at), for up to 8 parts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the bulk is not a multiple of `K`. If the length of the bulk is known at compile-time,
+    /// this is instead a compile-time error, since the parts would not all be of the same type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = *b"abcdefghijkl";
+    ///
+    /// let [a1, a2, a3, a4] = a.into_bulk()
+    ///     .split_into::<4>();
+    ///
+    /// let a1: [_; 3] = a1.collect();
+    /// let a4: [_; 3] = a4.collect();
+    ///
+    /// assert_eq!(&a1, b"abc");
+    /// assert_eq!(&a4, b"jkl");
     /// ```
     ///
-    /// Finally, even [`ControlFlow`] works, despite the fact that it
-    /// doesn't implement [`FromBulk`].
+    /// Splitting a dynamically sized bulk checks the length at runtime.
+    ///
+    /// ```should_panic
+    /// use bulks::*;
+    ///
+    /// let a: &[_] = &[1, 2, 3, 4, 5];
     ///
+    /// let [_, _] = a.into_bulk()
+    ///     .split_into::<2>();
     /// ```
+    ///
+    /// Splitting a bulk with a length known at compile-time into parts of unequal length does not compile.
+    ///
+    /// ```compile_fail
     /// # #![feature(generic_const_exprs)]
-    /// use core::ops::ControlFlow::{Break, Continue};
-    /// 
     /// use bulks::*;
     ///
-    /// let u = [Continue(1), Continue(2), Break(3), Continue(4), Continue(5)];
-    /// 
-    /// let v = u.into_bulk().try_collect_array();
-    /// assert_eq!(v, Break(3));
+    /// let a = [1, 2, 3, 4, 5];
     ///
-    /// let v = u.into_bulk().take([(); 2])
-    ///     .chain(u.into_bulk().skip([(); 3]))
-    ///     .try_collect_array();
-    /// assert_eq!(v, Continue([1, 2, 4, 5]));
+    /// let [_, _] = a.into_bulk()
+    ///     .split_into::<2>();
     /// ```
-    #[allow(clippy::type_complexity)]
-    #[must_use = "if you really need to exhaust the bulk, consider `.for_each(drop)` instead"]
-    fn try_collect_array(self) -> <<Self::Item as Try>::Residual as Residual<Self::Array<<Self::Item as Try>::Output>>>::TryType
+    #[track_caller]
+    fn split_into<const K: usize>(self) -> [<Self as SplitIntoBulk<SplitIntoLength<Self, K>, K>>::Part; K]
     where
-        Self: StaticBulk<Item: ~const Destruct + ~const Try<Residual: Residual<(), TryType: ~const Try> + Residual<Self::Array<<Self::Item as Try>::Output>, TryType: ~const Try> + ~const Destruct, Output: ~const Destruct>> + ~const Bulk
+        Self: ~const SplitIntoBulk<SplitIntoLength<Self, K>, K> + Sized
     {
-        Try::from_output(util::try_collect_array_with!(|pusher| self.try_for_each(pusher)?; for Self))
+        let len = self.len();
+        if K == 0 || !len.is_multiple_of(K)
+        {
+            IndivisibleLength { len, parts: K }.halt()
+        }
+        let l = self.length();
+        SplitIntoBulk::split_into(self, length::value::div(l, [(); K]))
     }
 
-    /// Resizes a bulk, padding it with copies of a given value of `element` if too short, or truncating it if too long.
-    /// The resuling bulk will have an exact length given by `n`.
+    /// Splits off the first element of the bulk, and returns it along with the rest of the bulk.
+    ///
+    /// If the bulk is known to be non-empty at compile-time, the first element is returned as is.
+    /// Otherwise it's [`None`] if the bulk is empty. The rest of the bulk is one element shorter,
+    /// so if the length of the bulk is `N`, the rest has a length of `N - 1`.
+    ///
+    /// Similar to [`<[T]>::split_first`](slice::split_first).
+    ///
+    /// # Examples
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
-    /// 
     /// use bulks::*;
     ///
-    /// let u = [1, 3, 3, 7];
-    /// 
-    /// let v = u.into_bulk()
-    ///     .resize([(); 6], 9)
-    ///     .collect_array();
-    /// assert_eq!(v, [1, 3, 3, 7, 9, 9]);
+    /// let a = [1, 2, 3];
+    ///
+    /// let (first, rest) = a.into_bulk()
+    ///     .split_first();
+    /// let rest: [_; 2] = rest.collect();
+    ///
+    /// assert_eq!(first, 1);
+    /// assert_eq!(rest, [2, 3]);
+    ///
+    /// let (first, rest) = a[..0].bulk()
+    ///     .split_first();
+    ///
+    /// assert_eq!(first, None);
+    /// assert!(rest.is_empty());
     /// ```
-    fn resize<N>(self, n: N, element: Self::Item) -> Resize<Self, N::Length<()>>
+    #[allow(clippy::type_complexity)]
+    fn split_first(self) -> (
+        Head<Self>,
+        <Self as SplitBulk<length::value::Min<Value<BulkLength<Self>>, [(); 1]>>>::Right
+    )
     where
-        Self: Sized,
-        Self::Item: Copy,
-        N: LengthValue
+        Self: ~const SplitBulk<length::value::Min<Value<BulkLength<Self>>, [(); 1]>, Left: ~const Bulk> + Sized,
+        Self::Item: ~const Destruct,
+        length::Min<Self::MinLength, [(); 1]>: ~const HeadLength
     {
-        Resize::new(self, n, element)
+        let l = self.length();
+        let (first, rest) = SplitBulk::split_at(self, length::value::min(l, [(); 1]));
+        (<length::Min<Self::MinLength, [(); 1]> as HeadLength>::head(first.first()), rest)
     }
 
-    /// Resizes a bulk, padding it with the output of `padder` if too short, or truncating it if too long.
-    /// The resuling bulk will have an exact length given by `n`.
+    /// Splits off the last element of the bulk, and returns it along with the rest of the bulk.
+    ///
+    /// If the bulk is known to be non-empty at compile-time, the last element is returned as is.
+    /// Otherwise it's [`None`] if the bulk is empty. The rest of the bulk is one element shorter,
+    /// so if the length of the bulk is `N`, the rest has a length of `N - 1`.
+    ///
+    /// Similar to [`<[T]>::split_last`](slice::split_last).
+    ///
+    /// # Examples
     ///
     /// ```
     /// # #![feature(generic_const_exprs)]
-    /// 
     /// use bulks::*;
     ///
-    /// let u = [1, 3, 3, 7];
-    /// 
-    /// let v = u.into_bulk()
-    ///     .resize_with([(); 6], || 9)
-    ///     .collect_array();
-    /// assert_eq!(v, [1, 3, 3, 7, 9, 9]);
+    /// let a = [1, 2, 3];
+    ///
+    /// let (last, rest) = a.into_bulk()
+    ///     .split_last();
+    /// let rest: [_; 2] = rest.collect();
+    ///
+    /// assert_eq!(last, 3);
+    /// assert_eq!(rest, [1, 2]);
     /// ```
-    fn resize_with<F, N>(self, n: N, padder: F) -> ResizeWith<Self, F, N::Length<()>>
+    #[allow(clippy::type_complexity)]
+    fn split_last(self) -> (
+        Head<Self>,
+        <Self as SplitBulk<length::value::SaturatingSub<Value<BulkLength<Self>>, [(); 1]>>>::Left
+    )
     where
-        Self: Sized,
-        N: LengthValue,
-        F: FnMut() -> Self::Item
+        Self: ~const SplitBulk<length::value::SaturatingSub<Value<BulkLength<Self>>, [(); 1]>, Right: ~const Bulk> + Sized,
+        Self::Item: ~const Destruct,
+        length::Min<Self::MinLength, [(); 1]>: ~const HeadLength
     {
-        ResizeWith::new(self, n, padder)
+        let (rest, last) = self.rsplit_at([(); 1]);
+        (<length::Min<Self::MinLength, [(); 1]> as HeadLength>::head(last.first()), rest)
     }
 
-    /// Reverses a bulks's direction.
+    /// Splits off the first `K` elements of the bulk as an array, and returns it along with the rest of the bulk.
     ///
-    /// Usually, bulks span from left to right. After using `rev()`,
-    /// a bulk will instead span from right to left.
-    /// 
-    /// Similar to [`Iterator::rev`].
+    /// The rest of the bulk is `K` elements shorter, so if the length of the bulk is `N`, the rest has a length of `N - K`.
+    ///
+    /// Similar to [`<[T]>::split_first_chunk`](slice::split_first_chunk).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `K` is zero, or if the bulk has fewer than `K` elements.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let a = [1, 2, 3];
+    /// let a = [1, 2, 3, 4, 5];
     ///
-    /// let b: [_; _] = a.into_bulk().rev().collect();
+    /// let (head, rest) = a.into_bulk()
+    ///     .split_first_n::<2>();
+    /// let rest: [_; 3] = rest.collect();
     ///
-    /// assert_eq!(b, [3, 2, 1]);
+    /// assert_eq!(head, [1, 2]);
+    /// assert_eq!(rest, [3, 4, 5]);
     /// ```
-    #[inline]
     #[track_caller]
-    #[doc(alias = "reverse")]
-    fn rev(self) -> Rev<Self>
+    fn split_first_n<const K: usize>(self) -> ([Self::Item; K], <Self as SplitBulk<[(); K]>>::Right)
     where
-        Self: Sized,
-        Self: DoubleEndedBulk
+        Self: ~const SplitBulk<[(); K], Left: ~const Bulk> + Sized,
+        Self::Item: ~const Destruct
     {
-        Rev::new(self)
+        let len = self.len();
+        if len < K
+        {
+            OutOfRange { i: K, len }.halt()
+        }
+        let (head, rest) = SplitBulk::split_at(self, [(); K]);
+        match head.array_chunks::<K>().first()
+        {
+            Some(head) => (head, rest),
+            None => unreachable!()
+        }
     }
 
-    /// Creates a bulk which copies all of its elements.
+    /// Creates a bulk that yields the elements of this bulk rotated such that the first `n` elements move to the end,
+    /// while the last `len - n` elements move to the front.
     ///
-    /// This is useful when you have a bulk of `&T`, but you need a
-    /// bulk of `T`.
-    /// 
-    /// Similar to [`Iterator::copied`].
+    /// The element at index `n` will be the first element yielded by the returned bulk.
+    ///
+    /// Unlike [`<[T]>::rotate_left`](slice::rotate_left), this does not move anything in memory. The bulk is consumed,
+    /// split at `n`, and the two halves are returned in reverse order as a [`Chain`]. If both the length of the bulk and `n`
+    /// are known at compile-time, so is the length of each half.
+    ///
+    /// # Panics
+    ///
+    /// This function will panic if `n` is greater than the length of the bulk.
     ///
     /// # Examples
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let a = [1, 2, 3];
-    ///
-    /// let v_copied: [_; _] = a.bulk().copied().collect();
+    /// let a = ['a', 'b', 'c', 'd', 'e', 'f'];
     ///
-    /// // copied is the same as .map(|&x| x)
-    /// let v_map: [_; _] = a.bulk().map(|&x| x).collect();
+    /// let b: [_; _] = a.into_bulk()
+    ///     .rotate_left([(); 2])
+    ///     .collect();
     ///
-    /// assert_eq!(v_copied, [1, 2, 3]);
-    /// assert_eq!(v_map, [1, 2, 3]);
+    /// assert_eq!(b, ['c', 'd', 'e', 'f', 'a', 'b']);
     /// ```
-    #[inline]
     #[track_caller]
-    fn copied<'a, T>(self) -> Copied<Self>
+    fn rotate_left<L>(self, n: L) -> Chain<<Self as SplitBulk<L>>::Right, <Self as SplitBulk<L>>::Left>
     where
-        T: Copy + 'a,
-        Self: Sized + ~const Bulk<Item = &'a T>,
+        Self: ~const SplitBulk<L> + Sized,
+        L: LengthValue
     {
-        Copied::new(self)
+        let i = length::value::len(n);
+        let len = self.len();
+        if i > len
+        {
+            OutOfRange { i, len }.halt()
+        }
+        let (left, right) = SplitBulk::split_at(self, n);
+        Chain::new(right, left)
     }
 
-    /// Creates a bulk which [`clone`](Clone::clone)s all of its elements.
+    /// Creates a bulk that yields the elements of this bulk rotated such that the first `len - n` elements move to the end,
+    /// while the last `n` elements move to the front.
     ///
-    /// This is useful when you have a bulk of `&T`, but you need a
-    /// bulk of `T`.
+    /// The element at index `len - n` will be the first element yielded by the returned bulk.
     ///
-    /// There is no guarantee whatsoever about the `clone` method actually
-    /// being called *or* optimized away. So code should not depend on
-    /// either.
-    /// 
-    /// Similar to [`Iterator::cloned`].
+    /// Unlike [`<[T]>::rotate_right`](slice::rotate_right), this does not move anything in memory. The bulk is consumed,
+    /// split at `len - n`, and the two halves are returned in reverse order as a [`Chain`]. If both the length of the bulk and `n`
+    /// are known at compile-time, so is the length of each half.
     ///
-    /// # Examples
+    /// # Panics
     ///
-    /// Basic usage:
+    /// This function will panic if `n` is greater than the length of the bulk.
+    ///
+    /// # Examples
     ///
     /// ```
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let a = [1, 2, 3];
-    ///
-    /// let v_cloned: [_; _] = a.bulk().cloned().collect();
+    /// let a = ['a', 'b', 'c', 'd', 'e', 'f'];
     ///
-    /// // cloned is the same as .map(|&x| x), for integers
-    /// let v_map: [_; _] = a.bulk().map(|&x| x).collect();
+    /// let b: [_; _] = a.into_bulk()
+    ///     .rotate_right([(); 2])
+    ///     .collect();
     ///
-    /// assert_eq!(v_cloned, [1, 2, 3]);
-    /// assert_eq!(v_map, [1, 2, 3]);
+    /// assert_eq!(b, ['e', 'f', 'a', 'b', 'c', 'd']);
     /// ```
-    #[inline]
+    #[allow(clippy::type_complexity)]
     #[track_caller]
-    fn cloned<'a, T>(self) -> Cloned<Self>
+    fn rotate_right<L>(self, n: L) -> Chain<
+        <Self as SplitBulk<length::value::SaturatingSub<<BulkLength<Self> as Length>::Value, L>>>::Right,
+        <Self as SplitBulk<length::value::SaturatingSub<<BulkLength<Self> as Length>::Value, L>>>::Left
+    >
     where
-        T: Clone + 'a,
-        Self: Sized + ~const Bulk<Item = &'a T>,
+        Self: ~const SplitBulk<length::value::SaturatingSub<<BulkLength<Self> as Length>::Value, L>> + Sized,
+        L: LengthValue
     {
-        Cloned::new(self)
+        let i = length::value::len(n);
+        let len = self.len();
+        if i > len
+        {
+            OutOfRange { i, len }.halt()
+        }
+        let (left, right) = self.rsplit_at(n);
+        Chain::new(right, left)
     }
 
-    /// Returns a bulk of `N` elements of the bulk at a time.
+    /// Inserts an element at position `i` within the bulk, shifting all elements after it to the right.
     ///
-    /// The chunks do not overlap. If `N` does not divide the length of the
-    /// bulk, then the last up to `N-1` elements will be omitted or the remainder
-    /// can then be retrieved from [`.into_remainder()`][crate::ArrayChunks::into_remainder]
-    /// or [`.collect_with_remainder()`][crate::ArrayChunks::collect_with_remainder]
-    /// 
-    /// Similar to [`Iterator::array_chunks`].
+    /// This is done by splitting the bulk at `i`, and chaining the element in between the two halves,
+    /// so if the length of the bulk is `N`, the returned bulk has a length of `N + 1`.
+    ///
+    /// Similar to [`Vec::insert`](std::vec::Vec::insert).
     ///
     /// # Panics
     ///
-    /// Panics if `N` is zero.
+    /// Panics if `i` is greater than the length of the bulk. If both are known at compile-time, this is instead a compile-time error.
     ///
     /// # Examples
     ///
-    /// Basic usage:
-    ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let bulk = b"lorem".bulk()
-    ///     .copied()
-    ///     .array_chunks();
-    /// 
-    /// let (c, r) = bulk.collect_with_remainder::<[_; _], _>();
-    /// 
-    /// let r: Vec<_> = r.collect();
-    /// 
-    /// assert_eq!(c, [[b'l', b'o'], [b'r', b'e']]);
-    /// assert_eq!(r, [b'm']);
-    /// ```
+    /// let a = [1, 2, 4];
     ///
+    /// let b: [_; 4] = a.into_bulk()
+    ///     .insert_at([(); 2], 3)
+    ///     .collect();
+    ///
+    /// assert_eq!(b, [1, 2, 3, 4]);
     /// ```
+    ///
+    /// Inserting past the end of a bulk with a length known at compile-time does not compile.
+    ///
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
     ///
-    /// let data = [1, 1, 2, -2, 6, 0, 3, 1];
-    /// //          ^-----^  ^------^
-    /// for [x, y, z] in data.bulk().array_chunks()
-    /// {
-    ///     assert_eq!(x + y + z, 4);
-    /// }
+    /// let a = [1, 2, 4];
+    ///
+    /// let b: [_; 4] = a.into_bulk()
+    ///     .insert_at([(); 4], 3)
+    ///     .collect();
     /// ```
-    #[inline]
+    #[allow(clippy::type_complexity)]
     #[track_caller]
-    fn array_chunks<const N: usize>(self) -> ArrayChunks<Self, N>
+    fn insert_at<L>(self, i: L, x: Self::Item) -> Chain<Chain<<Self as SplitBulk<L>>::Left, Once<Self::Item>>, <Self as SplitBulk<L>>::Right>
     where
-        Self: Sized,
+        Self: ~const SplitBulk<L> + Sized,
+        Self::MaxLength: InBounds<L>,
+        L: LengthValue
     {
-        ArrayChunks::new(self)
+        let n = length::value::len(i);
+        let len = self.len();
+        if n > len
+        {
+            OutOfRange { i: n, len }.halt()
+        }
+        let (left, right) = SplitBulk::split_at(self, i);
+        Chain::new(Chain::new(left, crate::once(x)), right)
     }
 
-    /// Splits a bulk in two at a specified index.
-    /// 
-    /// # Example
-    /// 
+    /// Removes the element at position `i` within the bulk, shifting all elements after it to the left.
+    ///
+    /// Returns the removed element, along with the rest of the bulk. This is done by splitting the bulk around `i`,
+    /// and chaining the two outer parts back together, so if the length of the bulk is `N`, the rest has a length of `N - 1`.
+    ///
+    /// Similar to [`Vec::remove`](std::vec::Vec::remove).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `i` is out of bounds. If both `i` and the length of the bulk are known at compile-time, this is instead a compile-time error.
+    ///
+    /// # Examples
+    ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a = b"leftright";
-    /// 
-    /// let (a1, a2) = a.bulk()
-    ///     .copied()
-    ///     .split_at([(); 4]);
-    /// 
-    /// let left: [_; _] = a1.collect();
-    /// let right: [_; _] = a2.collect();
-    /// 
-    /// assert_eq!(&left, b"left");
-    /// assert_eq!(&right, b"right");
+    ///
+    /// let a = [0x01, 0x02, 0x03, 0xff];
+    ///
+    /// let (checksum, payload) = a.into_bulk()
+    ///     .remove_at([(); 3]);
+    /// let payload: [_; 3] = payload.collect();
+    ///
+    /// assert_eq!(checksum, 0xff);
+    /// assert_eq!(payload, [0x01, 0x02, 0x03]);
+    /// ```
+    ///
+    /// Removing past the end of a bulk with a length known at compile-time does not compile.
+    ///
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [0x01, 0x02, 0x03];
+    ///
+    /// let (checksum, payload) = a.into_bulk()
+    ///     .remove_at([(); 3]);
     /// ```
+    #[allow(clippy::type_complexity)]
     #[track_caller]
-    fn split_at<L>(self, n: L) -> (Self::Left, Self::Right)
+    fn remove_at<L>(self, i: L) -> (
+        Self::Item,
+        Chain<<Self as SplitBulk<L>>::Left, <<Self as SplitBulk<L>>::Right as SplitBulk<[(); 1]>>::Right>
+    )
     where
-        Self: ~const SplitBulk<L> + Sized,
+        Self: ~const SplitBulk<L, Right: ~const SplitBulk<[(); 1], Left: ~const Bulk>> + Sized,
+        Self::Item: ~const Destruct,
+        Self::MaxLength: InBounds<length::value::Add<L, [(); 1]>>,
         L: LengthValue
     {
-        SplitBulk::split_at(self, n)
+        let n = length::value::len(i);
+        let len = self.len();
+        if n >= len
+        {
+            OutOfRange { i: n, len }.halt()
+        }
+        let (left, right) = SplitBulk::split_at(self, i);
+        let (removed, right) = SplitBulk::split_at(right, [(); 1]);
+        match removed.first()
+        {
+            Some(x) => (x, Chain::new(left, right)),
+            None => unreachable!()
+        }
     }
 
-    /// Splits a bulk in two at a specified reversed index.
-    /// 
-    /// # Example
-    /// 
+    /// Replaces the elements in `range` with the elements of another bulk.
+    ///
+    /// Returns the removed elements, along with the spliced bulk. This is done by splitting the bulk at the start
+    /// and end of `range`, and chaining `other` in between the two outer parts. If the lengths of the bulk, `other` and `range`
+    /// are known at compile-time, so are the lengths of both returned bulks.
+    ///
+    /// Similar to [`Vec::splice`](std::vec::Vec::splice).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of `range` is greater than its end, or if its end is greater than the length of the bulk.
+    /// If these are all known at compile-time, this is instead a compile-time error.
+    ///
+    /// # Examples
+    ///
     /// ```
     /// # #![feature(generic_const_exprs)]
     /// use bulks::*;
-    /// 
-    /// let a = b"leftright";
-    /// 
-    /// let (a1, a2) = a.bulk()
-    ///     .copied()
-    ///     .rsplit_at([(); 5]);
-    /// 
-    /// let left: [_; _] = a1.collect();
-    /// let right: [_; _] = a2.collect();
-    /// 
-    /// assert_eq!(&left, b"left");
-    /// assert_eq!(&right, b"right");
+    ///
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// let (removed, spliced) = a.into_bulk()
+    ///     .splice(bulks::range([(); 1], [(); 3]), [7, 8, 9]);
+    /// let removed: [_; 2] = removed.collect();
+    /// let spliced: [_; 5] = spliced.collect();
+    ///
+    /// assert_eq!(removed, [2, 3]);
+    /// assert_eq!(spliced, [1, 7, 8, 9, 4]);
+    /// ```
+    ///
+    /// Splicing a range that ends past the end of a bulk with a length known at compile-time does not compile.
+    ///
+    /// ```compile_fail
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3, 4];
+    ///
+    /// let (removed, spliced) = a.into_bulk()
+    ///     .splice(bulks::range([(); 3], [(); 5]), [7, 8, 9]);
     /// ```
+    #[allow(clippy::type_complexity)]
     #[track_caller]
-    fn rsplit_at<L>(self, n: L) -> (Self::Left, Self::Right)
+    fn splice<S, E, U>(self, range: Range<S, E>, other: U) -> (
+        <<Self as SplitBulk<S::Value>>::Right as SplitBulk<length::value::Sub<E::Value, S::Value>>>::Left,
+        Chain<
+            Chain<<Self as SplitBulk<S::Value>>::Left, U::IntoBulk>,
+            <<Self as SplitBulk<S::Value>>::Right as SplitBulk<length::value::Sub<E::Value, S::Value>>>::Right
+        >
+    )
     where
-        Self: ~const SplitBulk<length::value::SaturatingSub<<BulkLength<Self> as Length>::Value, L>> + Sized,
-        L: LengthValue
+        Self: ~const SplitBulk<S::Value, Right: ~const SplitBulk<length::value::Sub<E::Value, S::Value>>> + Sized,
+        S: Length<Elem = ()> + ?Sized,
+        E: InBounds<S::Value> + ?Sized,
+        Self::MaxLength: InBounds<E::Value>,
+        U: ~const IntoBulk<Item = Self::Item>
     {
-        let l = self.length();
-        SplitBulk::split_at(self, length::value::saturating_sub(l, n))
+        let start = range.start();
+        let end = range.end();
+        let len = self.len();
+        if length::value::len(start) > length::value::len(end)
+        {
+            OutOfRange { i: length::value::len(start), len: length::value::len(end) }.halt()
+        }
+        if length::value::len(end) > len
+        {
+            OutOfRange { i: length::value::len(end), len }.halt()
+        }
+        let (left, right) = SplitBulk::split_at(self, start);
+        let (removed, right) = SplitBulk::split_at(right, length::value::sub(end, start));
+        (removed, Chain::new(Chain::new(left, other.into_bulk()), right))
     }
     
     /// Consumes the bulk, and swaps two elements of it. Items must be mutably dereferenceable.
@@ -2713,6 +4756,97 @@ pub const trait Bulk: ~const IntoBulk<IntoBulk = Self>
         }
     }
 
+    /// Consumes the bulk, and writes each element into `dst` at the position given by the corresponding index in `indices`.
+    ///
+    /// This is the inverse of [`gather`](crate::slice::Bulk::gather). If an index occurs more than once, the last element written to it is kept.
+    ///
+    /// Returns an error if an index is out of bounds. The elements before it have then already been written.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the bulk differs from the number of indices. If both are known at compile-time, this is instead a compile-time error.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let mut dst = [0; 4];
+    ///
+    /// std::assert_matches!(
+    ///     [1, 2, 3].into_bulk()
+    ///         .scatter_into(&mut dst, [3, 0, 2]),
+    ///     Ok(())
+    /// );
+    /// std::assert_matches!(
+    ///     [4].into_bulk()
+    ///         .scatter_into(&mut dst, [4]),
+    ///     Err(OutOfRange { i: 4, len: 4 })
+    /// );
+    ///
+    /// assert_eq!(dst, [2, 0, 3, 1]);
+    /// ```
+    #[track_caller]
+    fn scatter_into<I>(self, dst: &mut [Self::Item], indices: I) -> Result<(), OutOfRange>
+    where
+        Self: Sized + SameLength<I::IntoBulk>,
+        Self::Item: ~const Destruct,
+        I: ~const IntoBulk<Item = usize, IntoBulk: ~const IntoCursor>
+    {
+        struct Closure<'a, T, I>
+        {
+            dst: &'a mut [T],
+            indices: I
+        }
+        const impl<'a, T, I> FnOnce<(T,)> for Closure<'a, T, I>
+        where
+            T: ~const Destruct,
+            I: ~const Iterator<Item = usize> + ~const Destruct
+        {
+            type Output = Result<(), OutOfRange>;
+
+            extern "rust-call" fn call_once(mut self, args: (T,)) -> Self::Output
+            {
+                self.call_mut(args)
+            }
+        }
+        const impl<'a, T, I> FnMut<(T,)> for Closure<'a, T, I>
+        where
+            T: ~const Destruct,
+            I: ~const Iterator<Item = usize>
+        {
+            extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+            {
+                let Self { dst, indices } = self;
+                let len = dst.len();
+                let i = match indices.next()
+                {
+                    Some(i) => i,
+                    None => return Ok(())
+                };
+                if i >= len
+                {
+                    return Err(OutOfRange { i, len })
+                }
+                dst[i] = x;
+                Ok(())
+            }
+        }
+
+        let indices = indices.into_bulk();
+        let lhs = self.len();
+        let rhs = indices.len();
+        if lhs != rhs
+        {
+            LengthMismatch { lhs, rhs }.halt()
+        }
+        self.try_for_each(Closure {
+            dst,
+            indices: indices.into_cursor()
+        })
+    }
+
     fn sum_from<T>(self, from: T) -> T
     where
         T: ~const Add<Self::Item, Output = T> + ~const Destruct,
@@ -2730,6 +4864,70 @@ pub const trait Bulk: ~const IntoBulk<IntoBulk = Self>
     }
 }
 
+/// Calls `f` on the elements of `a` paired with those of `b`, until it returns something other than `neutral`.
+///
+/// Returns [`None`] if no such result is found before either of the bulks runs out.
+const fn compare_by<A, B, F, R>(a: A, b: B, f: F, neutral: R) -> Option<R>
+where
+    A: ~const Bulk<Item: ~const Destruct>,
+    B: ~const IntoCursor<Item: ~const Destruct>,
+    F: ~const FnMut(A::Item, B::Item) -> R + ~const Destruct,
+    R: ~const PartialEq + Copy + ~const Destruct
+{
+    struct Functor<I, F, R>
+    {
+        other: I,
+        f: F,
+        neutral: R
+    }
+    const impl<I, F, R, T> FnOnce<((), T,)> for Functor<I, F, R>
+    where
+        I: ~const Iterator<Item: ~const Destruct> + ~const Destruct,
+        F: ~const FnMut(T, I::Item) -> R + ~const Destruct,
+        R: ~const PartialEq + Copy + ~const Destruct,
+        T: ~const Destruct
+    {
+        type Output = ControlFlow<Option<R>>;
+
+        extern "rust-call" fn call_once(mut self, args: ((), T,)) -> Self::Output
+        {
+            self.call_mut(args)
+        }
+    }
+    const impl<I, F, R, T> FnMut<((), T,)> for Functor<I, F, R>
+    where
+        I: ~const Iterator<Item: ~const Destruct> + ~const Destruct,
+        F: ~const FnMut(T, I::Item) -> R + ~const Destruct,
+        R: ~const PartialEq + Copy + ~const Destruct,
+        T: ~const Destruct
+    {
+        extern "rust-call" fn call_mut(&mut self, ((), x,): ((), T,)) -> Self::Output
+        {
+            match self.other.next()
+            {
+                Some(y) => {
+                    let result = (self.f)(x, y);
+                    if result == self.neutral
+                    {
+                        ControlFlow::Continue(())
+                    }
+                    else
+                    {
+                        ControlFlow::Break(Some(result))
+                    }
+                },
+                None => ControlFlow::Break(None)
+            }
+        }
+    }
+
+    match a.try_fold((), Functor { other: b.into_cursor(), f, neutral })
+    {
+        ControlFlow::Break(result) => result,
+        ControlFlow::Continue(()) => None
+    }
+}
+
 #[derive(Clone, Copy, Debug, thiserror::Error)]
 pub struct OutOfRange
 {
@@ -2739,7 +4937,7 @@ pub struct OutOfRange
 
 impl OutOfRange
 {
-    const fn halt(self) -> !
+    pub(crate) const fn halt(self) -> !
     {
         fn rt(oor: OutOfRange) -> !
         {
@@ -2764,6 +4962,130 @@ impl Display for OutOfRange
     }
 }
 
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+pub struct DuplicateIndex
+{
+    pub i: usize
+}
+
+impl Display for DuplicateIndex
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        let Self { i } = self;
+        write!(f, "Duplicate index. Index {i} occurs more than once.")
+    }
+}
+
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+pub enum IndexError
+{
+    OutOfRange(#[from] OutOfRange),
+    DuplicateIndex(#[from] DuplicateIndex)
+}
+
+impl IndexError
+{
+    pub(crate) const fn halt(self) -> !
+    {
+        fn rt(err: IndexError) -> !
+        {
+            panic!("{err}")
+        }
+
+        const fn ct(err: IndexError) -> !
+        {
+            match err
+            {
+                IndexError::OutOfRange(_) => panic!("Index out of bounds."),
+                IndexError::DuplicateIndex(_) => panic!("Duplicate index.")
+            }
+        }
+
+        core::intrinsics::const_eval_select((self,), ct, rt)
+    }
+}
+
+impl Display for IndexError
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        match self
+        {
+            Self::OutOfRange(err) => err.fmt(f),
+            Self::DuplicateIndex(err) => err.fmt(f)
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+pub struct IndivisibleLength
+{
+    pub len: usize,
+    pub parts: usize
+}
+
+impl IndivisibleLength
+{
+    const fn halt(self) -> !
+    {
+        fn rt(indivisible: IndivisibleLength) -> !
+        {
+            panic!("{indivisible}")
+        }
+
+        const fn ct(_: IndivisibleLength) -> !
+        {
+            panic!("Length can't be split evenly.")
+        }
+
+        core::intrinsics::const_eval_select((self,), ct, rt)
+    }
+}
+
+impl Display for IndivisibleLength
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        let Self { len, parts } = self;
+        write!(f, "Length can't be split evenly. Length {len} is not a multiple of {parts}.")
+    }
+}
+
+#[derive(Clone, Copy, Debug, thiserror::Error)]
+pub struct LengthMismatch
+{
+    pub lhs: usize,
+    pub rhs: usize
+}
+
+impl LengthMismatch
+{
+    pub(crate) const fn halt(self) -> !
+    {
+        fn rt(mismatch: LengthMismatch) -> !
+        {
+            panic!("{mismatch}")
+        }
+
+        const fn ct(_: LengthMismatch) -> !
+        {
+            panic!("Length mismatch.")
+        }
+
+        core::intrinsics::const_eval_select((self,), ct, rt)
+    }
+}
+
+impl Display for LengthMismatch
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result
+    {
+        let Self { lhs, rhs } = self;
+        write!(f, "Length mismatch. Length {lhs} is not equal to {rhs}.")
+    }
+}
+
 #[cfg(test)]
 mod test
 {
@@ -2789,4 +5111,162 @@ mod test
         println!("mean = {mean}");
         println!("variance = {variance}");
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_rotate()
+    {
+        let a = vec![1, 2, 3, 4, 5];
+
+        let left: Vec<_> = a.clone().into_bulk().rotate_left(2).collect();
+        let right: Vec<_> = a.into_bulk().rotate_right(2).collect();
+
+        assert_eq!(left, [3, 4, 5, 1, 2]);
+        assert_eq!(right, [4, 5, 1, 2, 3]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_rotate_out_of_range()
+    {
+        let _ = [1, 2, 3].into_bulk().rotate_left(4);
+    }
+
+    #[test]
+    fn test_array_chunks_exact()
+    {
+        let a = [1, 2, 3, 4, 5, 6, 7];
+
+        let (chunks, remainder) = a.into_bulk().array_chunks_exact::<3>();
+        let chunks: [_; 2] = chunks.collect();
+        let remainder: [_; 1] = remainder.collect();
+        assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(remainder, [7]);
+
+        let (chunks, remainder) = a.as_slice().into_bulk().array_chunks_exact::<2>();
+        let chunks: Vec<_> = chunks.collect();
+        let remainder: Vec<_> = remainder.collect();
+        assert_eq!(chunks, [[&1, &2], [&3, &4], [&5, &6]]);
+        assert_eq!(remainder, [&7]);
+    }
+
+    #[test]
+    fn test_split_into()
+    {
+        let a = [1, 2, 3, 4, 5, 6];
+
+        let [x, y, z] = a.into_bulk().split_into::<3>();
+        let x: [_; 2] = x.collect();
+        let y: [_; 2] = y.collect();
+        let z: [_; 2] = z.collect();
+        assert_eq!([x, y, z], [[1, 2], [3, 4], [5, 6]]);
+
+        let [x, y] = a.as_slice().into_bulk().split_into::<2>();
+        let x: Vec<_> = x.collect();
+        let y: Vec<_> = y.collect();
+        assert_eq!(x, [&1, &2, &3]);
+        assert_eq!(y, [&4, &5, &6]);
+    }
+
+    #[test]
+    fn test_split_first()
+    {
+        let a = [1, 2, 3, 4];
+
+        let (first, rest) = a.into_bulk().split_first();
+        let rest: [_; 3] = rest.collect();
+        assert_eq!(first, 1);
+        assert_eq!(rest, [2, 3, 4]);
+
+        let (last, rest) = a.as_slice().into_bulk().split_last();
+        let rest: Vec<_> = rest.collect();
+        assert_eq!(last, Some(&4));
+        assert_eq!(rest, [&1, &2, &3]);
+
+        let (head, rest) = a.into_bulk().split_first_n::<3>();
+        let rest: [_; 1] = rest.collect();
+        assert_eq!(head, [1, 2, 3]);
+        assert_eq!(rest, [4]);
+    }
+
+    #[test]
+    fn test_edit()
+    {
+        let a = [1, 2, 4];
+
+        let b: [i32; 4] = a.into_bulk().insert_at([(); 2], 3).collect();
+        assert_eq!(b, [1, 2, 3, 4]);
+
+        let (x, c) = b.into_bulk().remove_at([(); 0]);
+        let c: [_; 3] = c.collect();
+        assert_eq!(x, 1);
+        assert_eq!(c, [2, 3, 4]);
+
+        let (removed, d) = b.as_slice().into_bulk().splice(crate::range(1, 3), &[0; 3]);
+        let removed: Vec<_> = removed.collect();
+        let d: Vec<_> = d.collect();
+        assert_eq!(removed, [&2, &3]);
+        assert_eq!(d, [&1, &0, &0, &0, &4]);
+    }
+
+    #[test]
+    fn test_cmp()
+    {
+        let a = [1, 2, 3];
+
+        assert!(a.into_bulk().eq(a));
+        assert!(a.into_bulk().ne([1, 2]));
+        assert!(a.as_slice().into_bulk().eq(&[1, 2, 3]));
+
+        assert_eq!(a.into_bulk().cmp([1, 2]), core::cmp::Ordering::Greater);
+        assert_eq!(a.into_bulk().cmp([1, 3]), core::cmp::Ordering::Less);
+        assert_eq!(a.into_bulk().cmp(a), core::cmp::Ordering::Equal);
+
+        assert!(a.into_bulk().lt([2]));
+        assert!(a.into_bulk().le(a));
+        assert!(a.into_bulk().gt([0, 5, 5, 5]));
+        assert!(a.into_bulk().ge([1, 2]));
+
+        assert_eq!([1.0, f64::NAN].into_bulk().partial_cmp([1.0, 2.0]), None);
+        assert!(a.into_bulk().eq_by([2, 4, 6], |x, y| 2*x == y));
+
+        const {
+            assert!([1, 2, 3].into_bulk().eq([1, 2, 3]));
+            assert!(![1, 2, 3].into_bulk().eq([1, 2]));
+            assert!([1, 2].into_bulk().lt([1, 3]));
+        }
+        const CMP: core::cmp::Ordering = [1, 2].into_bulk().cmp([1]);
+        assert_eq!(CMP, core::cmp::Ordering::Greater);
+
+        const SQUARES: [u8; 4] = [0, 1, 4, 9];
+        const fn is_square_of(x: &u8, y: u8) -> bool
+        {
+            *x == y*y
+        }
+        const {
+            assert!(SQUARES.bulk().eq(&[0, 1, 4, 9]));
+            assert!(SQUARES.bulk().eq_by([0, 1, 2, 3], is_square_of));
+            assert!(SQUARES.as_slice().into_bulk().eq(&[0, 1, 4, 9]));
+            assert!(SQUARES.as_slice().into_bulk().gt(SQUARES.split_at(2).0));
+            assert!(SQUARES.bulk().lt(SQUARES.split_at(3).1));
+        }
+    }
+
+    #[test]
+    fn test_is_sorted()
+    {
+        assert!([1, 2, 2, 3].into_bulk().is_sorted());
+        assert!(![1, 2, 2, 3].into_bulk().is_strictly_sorted());
+        assert!([1, 2, 3].as_slice().into_bulk().is_strictly_sorted());
+        assert!(![3, 1].into_bulk().is_sorted());
+        assert!([3, 2, 1].into_bulk().rev().is_sorted());
+        assert!([3, 2, 1].into_bulk().is_sorted_by(|a, b| a >= b));
+        assert!(["a", "bb", "ccc"].into_bulk().is_sorted_by_key(str::len));
+
+        const {
+            assert!([1, 2, 2, 3].into_bulk().is_sorted());
+            assert!(![1, 2, 2, 3].into_bulk().is_strictly_sorted());
+            assert!(![3, 1].into_bulk().is_sorted());
+        }
+    }
+}
@@ -5,10 +5,10 @@ use core::ops::{Index, IndexMut, Range, RangeFrom, RangeFull, RangeInclusive, Ra
 use array_trait::AsSlice;
 use array_trait::length::Length;
 
-use crate::{AsBulk, Bulk,  CollectionAdapter, CollectionStrategy, FromBulk, IntoBulk, TryCollectionStrategy};
+use crate::{AsBulk, BoundedArray, Bulk,  CollectionAdapter, CollectionStrategy, FromBulk, IntoBulk, TryCollectionStrategy};
 
-pub(crate) const trait Collection<T, L: Nearest + ?Sized> = ~const IntoBulk<Item = T, IntoBulk: ~const Bulk<Item = T, /*MinLength: Length<Intersect<L> = L>,*/ MaxLength = L>>
-    + ~const FromBulk<L::NearestStrategy<T>>
+pub(crate) const trait Collection<T, L: Nearest<LMAX, LMIN> + ?Sized, LMAX: Length<Elem = ()> + ?Sized, LMIN: Length<Elem = ()> + ?Sized> = ~const IntoBulk<Item = T, IntoBulk: ~const Bulk<Item = T, /*MinLength: Length<Intersect<L> = L>,*/ MaxLength = LMAX>>
+    + ~const FromBulk<<L as Nearest<LMAX, LMIN>>::NearestStrategy<T>>
     + ~const AsBulk
     + ~const AsSlice<Elem = T>
     + ~const AsRef<[T]>
@@ -27,15 +27,18 @@ pub(crate) const trait Collection<T, L: Nearest + ?Sized> = ~const IntoBulk<Item
     message = "an array cannot be collected from dynamically sized bulk of length `{Self}`",
     label = "an array cannot be collected from bulk"
 )]
-pub const trait Nearest: Length<Elem = ()>
+pub const trait Nearest<LMAX = Self, LMIN = LMAX>: Length<Elem = ()>
+where
+    LMAX: Length<Elem = ()> + ?Sized,
+    LMIN: Length<Elem = ()> + ?Sized
 {
     #[allow(private_bounds)]
-    type Nearest<T>: ~const Collection<T, Self>
+    type Nearest<T>: ~const Collection<T, Self, LMAX, LMIN>
         + ~const FromBulk<Self::NearestStrategy<T>>
     where
         T: ~const Destruct;
     #[allow(private_bounds)]
-    type TryNearest<T>: ~const Collection<<T as Try>::Output, Self>
+    type TryNearest<T>: ~const Collection<<T as Try>::Output, Self, LMAX, LMIN>
         + ~const FromBulk<Self::TryNearestStrategy<T>>
         + ~const FromBulk<Self::NearestStrategy<<T as Try>::Output>>
         + const From<Self::Nearest<<T as Try>::Output>> + const Into<Self::Nearest<<T as Try>::Output>>
@@ -44,31 +47,31 @@ pub const trait Nearest: Length<Elem = ()>
         T: ~const Try<Output: ~const Destruct>;
 
     #[allow(private_bounds)]
-    type NearestFrom<B>: ~const Collection<B::Item, Self>
+    type NearestFrom<B>: ~const Collection<B::Item, Self, LMAX, LMIN>
         + ~const FromBulk<Self::NearestStrategyFrom<B>>
         + ~const FromBulk<Self::NearestStrategy<B::Item>>
         + const From<Self::Nearest<B::Item>> + const Into<Self::Nearest<B::Item>>
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Destruct>;
+        B: ~const Bulk<MinLength = LMIN, MaxLength = LMAX, Item: ~const Destruct>;
     #[allow(private_bounds)]
-    type TryNearestFrom<B>: ~const Collection<<B::Item as Try>::Output, Self>
+    type TryNearestFrom<B>: ~const Collection<<B::Item as Try>::Output, Self, LMAX, LMIN>
         + ~const FromBulk<Self::TryNearestStrategyFrom<B>>
         + ~const FromBulk<Self::TryNearestStrategy<B::Item>>
         + ~const FromBulk<Self::NearestStrategy<<B::Item as Try>::Output>>
         + const From<Self::TryNearest<B::Item>> + const Into<Self::TryNearest<B::Item>>
         + const From<Self::Nearest<<B::Item as Try>::Output>> + const Into<Self::Nearest<<B::Item as Try>::Output>>
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Try<Output: ~const Destruct>>;
+        B: ~const Bulk<MinLength = LMIN, MaxLength = LMAX, Item: ~const Try<Output: ~const Destruct>>;
 
     #[allow(private_bounds)]
-    type NearestStrategy<T>: ~const CollectionStrategy<Self, Self, Self::Nearest<T>>
+    type NearestStrategy<T>: ~const CollectionStrategy<LMIN, LMAX, Self::Nearest<T>>
         + CollectionAdapter<Elem = T>
         + ?Sized;
     #[allow(private_bounds)]
-    type TryNearestStrategy<T>: ~const TryCollectionStrategy<Self, Self, Self::TryNearest<T>>
-        + ~const TryCollectionStrategy<Self, Self, Self::Nearest<<T as Try>::Output>>
-        + ~const CollectionStrategy<Self, Self, Self::TryNearest<T>>
-        + ~const CollectionStrategy<Self, Self, Self::Nearest<<T as Try>::Output>>
+    type TryNearestStrategy<T>: ~const TryCollectionStrategy<LMIN, LMAX, Self::TryNearest<T>>
+        + ~const TryCollectionStrategy<LMIN, LMAX, Self::Nearest<<T as Try>::Output>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::TryNearest<T>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::Nearest<<T as Try>::Output>>
         + CollectionAdapter<Elem = <T as Try>::Output>
         + ?Sized
     where
@@ -77,33 +80,35 @@ pub const trait Nearest: Length<Elem = ()>
     #[allow(private_bounds)]
     type NearestStrategyFrom<B>: ~const CollectionStrategy<B::MinLength, B::MaxLength, Self::NearestFrom<B>>
         + ~const CollectionStrategy<B::MinLength, B::MaxLength, Self::Nearest<B::Item>>
-        + ~const CollectionStrategy<Self, Self, Self::NearestFrom<B>>
-        + ~const CollectionStrategy<Self, Self, Self::Nearest<B::Item>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::NearestFrom<B>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::Nearest<B::Item>>
         + CollectionAdapter<Elem = B::Item>
         + ?Sized
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Destruct>;
+        B: ~const Bulk<MinLength = LMIN, MaxLength = LMAX, Item: ~const Destruct>;
     #[allow(private_bounds)]
     type TryNearestStrategyFrom<B>: ~const TryCollectionStrategy<B::MinLength, B::MaxLength, Self::TryNearestFrom<B>>
         + ~const TryCollectionStrategy<B::MinLength, B::MaxLength, Self::TryNearest<B::Item>>
         + ~const TryCollectionStrategy<B::MinLength, B::MaxLength, Self::Nearest<<B::Item as Try>::Output>>
-        + ~const TryCollectionStrategy<Self, Self, Self::TryNearestFrom<B>>
-        + ~const TryCollectionStrategy<Self, Self, Self::TryNearest<B::Item>>
-        + ~const TryCollectionStrategy<Self, Self, Self::Nearest<<B::Item as Try>::Output>>
+        + ~const TryCollectionStrategy<LMIN, LMAX, Self::TryNearestFrom<B>>
+        + ~const TryCollectionStrategy<LMIN, LMAX, Self::TryNearest<B::Item>>
+        + ~const TryCollectionStrategy<LMIN, LMAX, Self::Nearest<<B::Item as Try>::Output>>
         + ~const CollectionStrategy<B::MinLength, B::MaxLength, Self::TryNearestFrom<B>>
         + ~const CollectionStrategy<B::MinLength, B::MaxLength, Self::TryNearest<B::Item>>
         + ~const CollectionStrategy<B::MinLength, B::MaxLength, Self::Nearest<<B::Item as Try>::Output>>
-        + ~const CollectionStrategy<Self, Self, Self::TryNearestFrom<B>>
-        + ~const CollectionStrategy<Self, Self, Self::TryNearest<B::Item>>
-        + ~const CollectionStrategy<Self, Self, Self::Nearest<<B::Item as Try>::Output>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::TryNearestFrom<B>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::TryNearest<B::Item>>
+        + ~const CollectionStrategy<LMIN, LMAX, Self::Nearest<<B::Item as Try>::Output>>
         + CollectionAdapter<Elem = <B::Item as Try>::Output>
         + ?Sized
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Try<Output: ~const Destruct>>;
+        B: ~const Bulk<MinLength = LMIN, MaxLength = LMAX, Item: ~const Try<Output: ~const Destruct>>;
 }
 
 #[cfg(feature = "alloc")]
-impl Nearest for [()]
+impl<LMIN> Nearest<[()], LMIN> for [()]
+where
+    LMIN: Length<Elem = ()> + ?Sized
 {
     type Nearest<T> = alloc::vec::Vec<T>;
     type TryNearest<T> = alloc::vec::Vec<<T as Try>::Output>
@@ -117,17 +122,17 @@ impl Nearest for [()]
 
     type NearestFrom<B> = Self::Nearest<B::Item>
     where
-        B: Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>>;
+        B: Bulk<MinLength = LMIN, MaxLength = Self>;
     type TryNearestFrom<B> = Self::TryNearest<B::Item>
     where
-        B: Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: Try>;
+        B: Bulk<MinLength = LMIN, MaxLength = Self, Item: Try>;
 
     type NearestStrategyFrom<B> = Self::NearestStrategy<B::Item>
     where
-        B: Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>>;
+        B: Bulk<MinLength = LMIN, MaxLength = Self>;
     type TryNearestStrategyFrom<B> = Self::TryNearestStrategy<B::Item>
     where
-        B: Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: Try>;
+        B: Bulk<MinLength = LMIN, MaxLength = Self, Item: Try>;
 }
 const impl<const N: usize> Nearest for [(); N]
 {
@@ -145,17 +150,97 @@ const impl<const N: usize> Nearest for [(); N]
 
     type NearestFrom<B> = Self::Nearest<B::Item>
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Destruct>;
+        B: ~const Bulk<MinLength = Self, MaxLength = Self, Item: ~const Destruct>;
     type TryNearestFrom<B> = Self::TryNearest<B::Item>
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Try<Output: ~const Destruct>>;
+        B: ~const Bulk<MinLength = Self, MaxLength = Self, Item: ~const Try<Output: ~const Destruct>>;
 
     type NearestStrategyFrom<B> = Self::NearestStrategy<B::Item>
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Destruct>;
+        B: ~const Bulk<MinLength = Self, MaxLength = Self, Item: ~const Destruct>;
     type TryNearestStrategyFrom<B> = Self::TryNearestStrategy<B::Item>
     where
-        B: ~const Bulk<MinLength: Length<Intersect<B::MaxLength> = Self>, Item: ~const Try<Output: ~const Destruct>>;
+        B: ~const Bulk<MinLength = Self, MaxLength = Self, Item: ~const Try<Output: ~const Destruct>>;
 }
 
-pub trait CollectNearest = Bulk<MinLength: Length<Intersect<<Self as Bulk>::MaxLength>: Nearest>>;
\ No newline at end of file
+impl<LMIN, const N: usize> Nearest<[(); N], LMIN> for [()]
+where
+    LMIN: Length<Elem = ()> + ?Sized
+{
+    type Nearest<T> = BoundedArray<T, N>;
+    type TryNearest<T> = BoundedArray<<T as Try>::Output, N>
+    where
+        T: Try;
+
+    type NearestStrategy<T> = [T];
+    type TryNearestStrategy<T> = [<T as Try>::Output]
+    where
+        T: Try;
+
+    type NearestFrom<B> = Self::Nearest<B::Item>
+    where
+        B: Bulk<MinLength = LMIN, MaxLength = [(); N]>;
+    type TryNearestFrom<B> = Self::TryNearest<B::Item>
+    where
+        B: Bulk<MinLength = LMIN, MaxLength = [(); N], Item: Try>;
+
+    type NearestStrategyFrom<B> = Self::NearestStrategy<B::Item>
+    where
+        B: Bulk<MinLength = LMIN, MaxLength = [(); N]>;
+    type TryNearestStrategyFrom<B> = Self::TryNearestStrategy<B::Item>
+    where
+        B: Bulk<MinLength = LMIN, MaxLength = [(); N], Item: Try>;
+}
+
+/// The length a bulk is keyed on when collected with [`Bulk::collect_nearest`].
+///
+/// This is the length of the bulk if it is known at compile-time, and `[()]` otherwise.
+pub type NearestLength<B> = <<B as Bulk>::MaxLength as private::NearestLength<<B as Bulk>::MinLength>>::Length;
+
+pub trait CollectNearest = Bulk<MaxLength: private::NearestLength<<Self as Bulk>::MinLength, Length: Nearest<<Self as Bulk>::MaxLength, <Self as Bulk>::MinLength>>>;
+
+mod private
+{
+    use array_trait::length::Length;
+
+    pub trait NearestLength<MinLength>: Length<Elem = ()>
+    where
+        MinLength: Length<Elem = ()> + ?Sized
+    {
+        type Length: Length<Elem = ()> + ?Sized;
+    }
+
+    impl<MinLength> NearestLength<MinLength> for [()]
+    where
+        MinLength: Length<Elem = ()> + ?Sized
+    {
+        type Length = [()];
+    }
+    impl<const N: usize, const M: usize> NearestLength<[(); M]> for [(); N]
+    where
+        Exact<{N == M}>: Select<[(); N]>
+    {
+        type Length = <Exact<{N == M}> as Select<[(); N]>>::Length;
+    }
+
+    pub struct Exact<const EXACT: bool>;
+
+    pub trait Select<L>
+    where
+        L: Length<Elem = ()> + ?Sized
+    {
+        type Length: Length<Elem = ()> + ?Sized;
+    }
+    impl<L> Select<L> for Exact<true>
+    where
+        L: Length<Elem = ()> + ?Sized
+    {
+        type Length = L;
+    }
+    impl<L> Select<L> for Exact<false>
+    where
+        L: Length<Elem = ()> + ?Sized
+    {
+        type Length = [()];
+    }
+}
\ No newline at end of file
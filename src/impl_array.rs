@@ -2,7 +2,7 @@ use core::{marker::Destruct, mem::MaybeUninit, ops::Try};
 
 use array_trait::{length::{self, LengthValue}, same::Same};
 
-use crate::{Bulk, DoubleEndedBulk, IntoBulk, SplitBulk, StaticBulk, slice, util::{self, Guard}};
+use crate::{AsBulk, Bulk, DoubleEndedBulk, IndexError, IntoBulk, OutOfRange, SplitBulk, StaticBulk, slice, util::{self, Guard}};
 
 pub mod array
 {
@@ -23,6 +23,15 @@ pub mod array
     {
         pub(super) array: &'a mut [T; N]
     }
+
+    /// `K` distinct indices into an array of length `N`.
+    ///
+    /// The indices are verified on construction, so a constant with invalid indices fails to compile.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub struct DisjointIndices<const K: usize, const N: usize>
+    {
+        pub(super) indices: [usize; K]
+    }
 }
 
 macro_rules! impl_bulk {
@@ -435,6 +444,298 @@ impl_bulk!(
     }
 );
 
+impl<'a, T, const N: usize> array::Bulk<'a, T, N>
+{
+    /// Returns a bulk of `M` elements of the array at a time, as references to arrays.
+    ///
+    /// Like [`Bulk::array_chunks`], but the chunks point straight into the array instead of being copied.
+    /// The number of chunks is known at compile-time. If `M` does not divide `N`, then the last up to `M-1`
+    /// elements will be omitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let data = [1, 2, 3, 4, 5, 6];
+    ///
+    /// let chunks: [&[_; 3]; _] = data.bulk()
+    ///     .array_chunks()
+    ///     .collect();
+    ///
+    /// assert_eq!(chunks, [&[1, 2, 3], &[4, 5, 6]]);
+    /// ```
+    #[track_caller]
+    pub const fn array_chunks<const M: usize>(self) -> slice::ArrayChunks<'a, T, M, [(); N]>
+    {
+        let Self {array} = self;
+        slice::ArrayChunks::new(array)
+    }
+
+    /// Returns a bulk of overlapping windows of `M` elements of the array, as references to arrays.
+    ///
+    /// Like [`Bulk::map_windows`], but the windows point straight into the array instead of being copied
+    /// into a buffer. The number of windows is known at compile-time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let data = [1, 2, 3, 4];
+    ///
+    /// let sums: [_; _] = data.bulk()
+    ///     .array_windows()
+    ///     .map(|[a, b]: &[_; 2]| a + b)
+    ///     .collect();
+    ///
+    /// assert_eq!(sums, [3, 5, 7]);
+    /// ```
+    #[track_caller]
+    pub const fn array_windows<const M: usize>(self) -> slice::ArrayWindows<'a, T, M, [(); N]>
+    {
+        let Self {array} = self;
+        slice::ArrayWindows::new(array)
+    }
+
+    /// Returns a bulk over the elements of the array at the positions given by `indices`.
+    ///
+    /// See [`slice::Bulk::gather`] for more.
+    ///
+    /// # Panics
+    ///
+    /// Panics when an index that is out of bounds is reached.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let table = [1, 10, 100];
+    ///
+    /// let picked: [_; _] = table.bulk()
+    ///     .gather([2, 1, 2])
+    ///     .copied()
+    ///     .collect();
+    ///
+    /// assert_eq!(picked, [100, 10, 100]);
+    /// ```
+    pub const fn gather<I>(self, indices: I) -> slice::Gather<'a, T, I::IntoBulk>
+    where
+        I: ~const IntoBulk<Item = usize>
+    {
+        let Self {array} = self;
+        array.as_slice().bulk().gather(indices)
+    }
+
+    /// Returns a bulk over the elements of the array at the positions given by `indices`, checking every index in advance.
+    ///
+    /// See [`slice::Bulk::try_gather`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let table = [1, 10, 100];
+    ///
+    /// assert!(matches!(
+    ///     table.bulk()
+    ///         .try_gather([2, 3]),
+    ///     Err(OutOfRange { i: 3, len: 3 })
+    /// ));
+    /// ```
+    pub const fn try_gather<I>(self, indices: I) -> Result<slice::Gather<'a, T, I::IntoBulk>, OutOfRange>
+    where
+        I: ~const IntoBulk<Item = usize, IntoBulk: ~const Clone + ~const Destruct>
+    {
+        let Self {array} = self;
+        array.as_slice().bulk().try_gather(indices)
+    }
+}
+impl<'a, T, const N: usize> array::BulkMut<'a, T, N>
+{
+    /// Returns a bulk of `M` elements of the array at a time, as mutable references to arrays.
+    ///
+    /// Like [`Bulk::array_chunks`], but the chunks point straight into the array instead of being copied.
+    /// The number of chunks is known at compile-time. If `M` does not divide `N`, then the last up to `M-1`
+    /// elements will be omitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `M` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let mut data = [1, 2, 3, 4, 5, 6];
+    ///
+    /// data.bulk_mut()
+    ///     .array_chunks::<3>()
+    ///     .for_each(|chunk| chunk.reverse());
+    ///
+    /// assert_eq!(data, [3, 2, 1, 6, 5, 4]);
+    /// ```
+    #[track_caller]
+    pub const fn array_chunks<const M: usize>(self) -> slice::ArrayChunksMut<'a, T, M, [(); N]>
+    {
+        let Self {array} = self;
+        slice::ArrayChunksMut::new(array)
+    }
+
+    /// Rearranges the array in-place, such that the element at position `i` is moved from position `perm[i]`.
+    ///
+    /// See [`slice::BulkMut::permute`] for more.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let mut data = ['a', 'b', 'c'];
+    ///
+    /// std::assert_matches!(
+    ///     data.bulk_mut()
+    ///         .permute([1, 2, 0]),
+    ///     Ok(())
+    /// );
+    ///
+    /// assert_eq!(data, ['b', 'c', 'a']);
+    /// ```
+    pub const fn permute(self, perm: [usize; N]) -> Result<(), IndexError>
+    {
+        let Self {array} = self;
+        array.as_mut_slice().bulk_mut().permute(&perm)
+    }
+
+    /// Returns mutable references to the elements at several positions of the array at once.
+    ///
+    /// See [`slice::BulkMut::get_many_mut`] for more. If the indices are known at compile-time,
+    /// consider [`get_disjoint_mut`](array::BulkMut::get_disjoint_mut) instead.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let mut data = [1, 2, 3];
+    ///
+    /// let [a, b] = data.bulk_mut()
+    ///     .get_many_mut([2, 0])
+    ///     .unwrap();
+    /// *a *= 10;
+    /// *b += 1;
+    ///
+    /// assert_eq!(data, [2, 2, 30]);
+    /// ```
+    pub const fn get_many_mut<const K: usize>(self, indices: [usize; K]) -> Result<[&'a mut T; K], IndexError>
+    {
+        let Self {array} = self;
+        array.as_mut_slice().bulk_mut().get_many_mut(indices)
+    }
+
+    /// Returns mutable references to the elements at several positions of the array at once, without checking the indices.
+    ///
+    /// For a safe alternative, see [`get_many_mut`](array::BulkMut::get_many_mut).
+    ///
+    /// # Safety
+    ///
+    /// Every index must be less than `N`, and no index may occur more than once.
+    pub const unsafe fn get_many_unchecked_mut<const K: usize>(self, indices: [usize; K]) -> [&'a mut T; K]
+    {
+        let Self {array} = self;
+        unsafe {
+            array.as_mut_slice().bulk_mut().get_many_unchecked_mut(indices)
+        }
+    }
+
+    /// Returns mutable references to the elements at several positions of the array at once,
+    /// where the indices have already been verified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    ///
+    /// let [a, b] = data.bulk_mut()
+    ///     .get_disjoint_mut(const { array::DisjointIndices::new([3, 1]) });
+    /// core::mem::swap(a, b);
+    ///
+    /// assert_eq!(data, [1, 4, 3, 2]);
+    /// ```
+    ///
+    /// Invalid indices are caught at compile-time.
+    ///
+    /// ```compile_fail
+    /// use bulks::*;
+    ///
+    /// let mut data = [1, 2, 3, 4];
+    ///
+    /// let [a, b] = data.bulk_mut()
+    ///     .get_disjoint_mut(const { array::DisjointIndices::new([1, 1]) });
+    /// ```
+    pub const fn get_disjoint_mut<const K: usize>(self, indices: array::DisjointIndices<K, N>) -> [&'a mut T; K]
+    {
+        let Self {array} = self;
+        let array::DisjointIndices { indices } = indices;
+        unsafe {
+            array.as_mut_slice().bulk_mut().get_many_unchecked_mut(indices)
+        }
+    }
+}
+impl<const K: usize, const N: usize> array::DisjointIndices<K, N>
+{
+    /// Verifies that every index is less than `N`, and that no index occurs more than once.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the indices are invalid. When evaluated in a constant, this is a compile-time error.
+    #[track_caller]
+    pub const fn new(indices: [usize; K]) -> Self
+    {
+        match Self::try_new(indices)
+        {
+            Ok(indices) => indices,
+            Err(err) => err.halt()
+        }
+    }
+
+    /// Verifies that every index is less than `N`, and that no index occurs more than once.
+    ///
+    /// Returns an error if the indices are invalid.
+    pub const fn try_new(indices: [usize; K]) -> Result<Self, IndexError>
+    {
+        match crate::check_disjoint(&indices, N)
+        {
+            Ok(()) => Ok(Self { indices }),
+            Err(err) => Err(err)
+        }
+    }
+
+    /// Returns the indices.
+    pub const fn into_inner(self) -> [usize; K]
+    {
+        let Self { indices } = self;
+        indices
+    }
+}
+
 /*impl<T, const N: usize> StaticMapSpec<N> for array::IntoBulk<T, N>
 {
     fn map_collect_array<U>(self, f: impl FnMut(Self::Item) -> U) -> [U; N]
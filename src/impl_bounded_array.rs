@@ -0,0 +1,237 @@
+use core::{mem::MaybeUninit, ops::{Range, Try}, ptr};
+
+use array_trait::length::{self, LengthValue};
+
+use crate::{AsBulk, Bulk, BoundedArray, DoubleEndedBulk, IntoBulk, SplitBulk, slice};
+
+pub mod bounded_array
+{
+    use core::{mem::MaybeUninit, ops::Range};
+
+    use crate::BoundedArray;
+
+    pub struct IntoBulk<T, const N: usize>
+    {
+        pub(super) inner: BoundedArray<T, N>
+    }
+
+    /// An iterator that moves out of a [`BoundedArray`].
+    pub struct IntoIter<T, const N: usize>
+    {
+        pub(super) array: [MaybeUninit<T>; N],
+        pub(super) alive: Range<usize>
+    }
+}
+
+impl<T, const N: usize> bounded_array::IntoIter<T, N>
+{
+    /// Returns the remaining elements as a slice.
+    pub const fn as_slice(&self) -> &[T]
+    {
+        let Range { start, end } = self.alive;
+        unsafe {
+            core::slice::from_raw_parts(self.array.as_ptr().add(start).cast(), end - start)
+        }
+    }
+
+    /// Returns the remaining elements as a mutable slice.
+    pub const fn as_mut_slice(&mut self) -> &mut [T]
+    {
+        let Range { start, end } = self.alive;
+        unsafe {
+            core::slice::from_raw_parts_mut(self.array.as_mut_ptr().add(start).cast(), end - start)
+        }
+    }
+}
+
+impl<T, const N: usize> Iterator for bounded_array::IntoIter<T, N>
+{
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let i = self.alive.next()?;
+        Some(unsafe {
+            self.array[i].assume_init_read()
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let len = self.len();
+        (len, Some(len))
+    }
+}
+impl<T, const N: usize> ExactSizeIterator for bounded_array::IntoIter<T, N>
+{
+    fn len(&self) -> usize
+    {
+        self.alive.len()
+    }
+}
+impl<T, const N: usize> DoubleEndedIterator for bounded_array::IntoIter<T, N>
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        let i = self.alive.next_back()?;
+        Some(unsafe {
+            self.array[i].assume_init_read()
+        })
+    }
+}
+impl<T, const N: usize> Drop for bounded_array::IntoIter<T, N>
+{
+    fn drop(&mut self)
+    {
+        unsafe {
+            ptr::drop_in_place(self.as_mut_slice())
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for BoundedArray<T, N>
+{
+    type Item = T;
+    type IntoIter = bounded_array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let (array, len): ([MaybeUninit<T>; N], usize) = self.into_raw_parts();
+        bounded_array::IntoIter {
+            array,
+            alive: 0..len
+        }
+    }
+}
+
+impl<T, const N: usize> IntoIterator for bounded_array::IntoBulk<T, N>
+{
+    type Item = T;
+    type IntoIter = bounded_array::IntoIter<T, N>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        self.inner.into_iter()
+    }
+}
+impl<T, const N: usize> IntoBulk for BoundedArray<T, N>
+{
+    type IntoBulk = bounded_array::IntoBulk<T, N>;
+
+    fn into_bulk(self) -> Self::IntoBulk
+    {
+        bounded_array::IntoBulk {
+            inner: self
+        }
+    }
+}
+impl<'a, T, const N: usize> IntoBulk for &'a BoundedArray<T, N>
+{
+    type IntoBulk = slice::Bulk<'a, T>;
+
+    fn into_bulk(self) -> Self::IntoBulk
+    {
+        self.as_slice().bulk()
+    }
+}
+impl<'a, T, const N: usize> IntoBulk for &'a mut BoundedArray<T, N>
+{
+    type IntoBulk = slice::BulkMut<'a, T>;
+
+    fn into_bulk(self) -> Self::IntoBulk
+    {
+        self.as_mut_slice().bulk_mut()
+    }
+}
+impl<T, const N: usize> Bulk for bounded_array::IntoBulk<T, N>
+{
+    type MinLength = [(); 0];
+    type MaxLength = [(); N];
+
+    fn len(&self) -> usize
+    {
+        self.inner.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        self.inner.is_empty()
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().for_each(f);
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        self.into_iter().try_for_each(f)
+    }
+}
+
+impl<T, const N: usize> DoubleEndedBulk for bounded_array::IntoBulk<T, N>
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        Self: Sized,
+        F: FnMut(Self::Item)
+    {
+        self.into_iter().rev().for_each(f);
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self: Sized,
+        F: FnMut(Self::Item) -> R,
+        R: Try<Output = ()>
+    {
+        self.into_iter().rev().try_for_each(f)
+    }
+}
+
+impl<T, L, const N: usize> SplitBulk<L> for bounded_array::IntoBulk<T, N>
+where
+    L: LengthValue
+{
+    type Left = Self;
+    type Right = Self;
+
+    fn split_at(mut left: Self, n: L) -> (Self::Left, Self::Right)
+    where
+        Self: Sized
+    {
+        let Self { inner } = &mut left;
+
+        let n = length::value::len(n).min(inner.len());
+        let right = inner.split_off(n)
+            .into_bulk();
+
+        (left, right)
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a: BoundedArray<_, 6> = [1, 2, 3, 4, 5, 6].into_bulk()
+            .filter(|x| x % 2 == 1)
+            .collect();
+
+        let (left, right) = a.into_bulk()
+            .split_at(1);
+
+        let left: Vec<_> = left.collect();
+        let right: Vec<_> = right.rev().collect();
+
+        assert_eq!(left, [1]);
+        assert_eq!(right, [5, 3]);
+    }
+}
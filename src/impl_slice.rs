@@ -1,11 +1,15 @@
-use core::{marker::Destruct, ops::Try};
+use core::{marker::{Destruct, PhantomData}, mem::MaybeUninit, ops::Try};
 
-use array_trait::length::{self, LengthValue};
+use array_trait::length::{self, Length, LengthValue};
 
-use crate::{Bulk, IntoBulk, DoubleEndedBulk, SplitBulk};
+use crate::{Bulk, DuplicateIndex, IndexError, IntoBulk, IntoCursor, DoubleEndedBulk, LengthMismatch, OutOfRange, SplitBulk};
 
 pub mod slice
 {
+    use core::marker::PhantomData;
+
+    use array_trait::length::Length;
+
     pub struct Bulk<'a, T>
     where
         T: 'a
@@ -19,6 +23,54 @@ pub mod slice
     {
         pub(super) slice: &'a mut [T]
     }
+
+    /// A bulk over `N` elements of a slice at a time, borrowed straight from the slice.
+    ///
+    /// This `struct` is created by the `array_chunks` method on [`Bulk`] and [`array::Bulk`](crate::array::Bulk).
+    pub struct ArrayChunks<'a, T, const N: usize, L = [()]>
+    where
+        T: 'a,
+        L: Length<Elem = ()> + ?Sized
+    {
+        pub(super) chunks: &'a [[T; N]],
+        pub(super) length: PhantomData<L>
+    }
+
+    /// A bulk over `N` elements of a slice at a time, mutably borrowed straight from the slice.
+    ///
+    /// This `struct` is created by the `array_chunks` method on [`BulkMut`] and [`array::BulkMut`](crate::array::BulkMut).
+    pub struct ArrayChunksMut<'a, T, const N: usize, L = [()]>
+    where
+        T: 'a,
+        L: Length<Elem = ()> + ?Sized
+    {
+        pub(super) chunks: &'a mut [[T; N]],
+        pub(super) length: PhantomData<L>
+    }
+
+    /// A bulk over overlapping windows of `N` elements of a slice, borrowed straight from the slice.
+    ///
+    /// This `struct` is created by the `array_windows` method on [`Bulk`] and [`array::Bulk`](crate::array::Bulk).
+    pub struct ArrayWindows<'a, T, const N: usize, L = [()]>
+    where
+        T: 'a,
+        L: Length<Elem = ()> + ?Sized
+    {
+        pub(super) slice: &'a [T],
+        pub(super) length: PhantomData<L>
+    }
+
+    /// A bulk over the elements of a slice at the positions given by a bulk of indices.
+    ///
+    /// This `struct` is created by the `gather` method on [`Bulk`] and [`array::Bulk`](crate::array::Bulk).
+    pub struct Gather<'a, T, I>
+    where
+        T: 'a,
+        I: crate::Bulk<Item = usize>
+    {
+        pub(super) slice: &'a [T],
+        pub(super) indices: I
+    }
 }
 
 macro_rules! impl_bulk {
@@ -273,4 +325,1038 @@ impl_bulk!(
             slice.get_mut(length::value::len(n))
         }
     } 
-);
\ No newline at end of file
+);
+impl<'a, T> slice::Bulk<'a, T>
+{
+    /// Returns a bulk of `N` elements of the slice at a time, as references to arrays.
+    ///
+    /// Like [`Bulk::array_chunks`], but the chunks point straight into the slice instead of being copied into arrays.
+    /// If `N` does not divide the length of the slice, then the last up to `N-1` elements will be omitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let data: &[_] = &[1, 2, 3, 4, 5];
+    ///
+    /// let chunks: Vec<&[_; 2]> = data.bulk()
+    ///     .array_chunks()
+    ///     .collect();
+    ///
+    /// assert_eq!(chunks, [&[1, 2], &[3, 4]]);
+    /// ```
+    #[track_caller]
+    pub const fn array_chunks<const N: usize>(self) -> slice::ArrayChunks<'a, T, N>
+    {
+        let Self { slice } = self;
+        slice::ArrayChunks::new(slice)
+    }
+
+    /// Returns a bulk of overlapping windows of `N` elements of the slice, as references to arrays.
+    ///
+    /// Like [`Bulk::map_windows`], but the windows point straight into the slice instead of being copied into a buffer.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let data: &[_] = &[1, 2, 3, 4];
+    ///
+    /// let windows: Vec<&[_; 3]> = data.bulk()
+    ///     .array_windows()
+    ///     .collect();
+    ///
+    /// assert_eq!(windows, [&[1, 2, 3], &[2, 3, 4]]);
+    /// ```
+    #[track_caller]
+    pub const fn array_windows<const N: usize>(self) -> slice::ArrayWindows<'a, T, N>
+    {
+        let Self { slice } = self;
+        slice::ArrayWindows::new(slice)
+    }
+
+    /// Returns a bulk over the elements of the slice at the positions given by `indices`.
+    ///
+    /// The returned bulk has the same length as `indices`, and indices may occur more than once.
+    ///
+    /// # Panics
+    ///
+    /// Panics when an index that is out of bounds is reached. See [`try_gather`](slice::Bulk::try_gather) for checking the indices in advance.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let table: &[_] = &['a', 'b', 'c', 'd'];
+    ///
+    /// let picked: [_; _] = table.bulk()
+    ///     .gather([3, 0, 0, 2])
+    ///     .copied()
+    ///     .collect();
+    ///
+    /// assert_eq!(picked, ['d', 'a', 'a', 'c']);
+    /// ```
+    pub const fn gather<I>(self, indices: I) -> slice::Gather<'a, T, I::IntoBulk>
+    where
+        I: ~const IntoBulk<Item = usize>
+    {
+        let Self { slice } = self;
+        slice::Gather {
+            slice,
+            indices: indices.into_bulk()
+        }
+    }
+
+    /// Returns a bulk over the elements of the slice at the positions given by `indices`, checking every index in advance.
+    ///
+    /// Like [`gather`](slice::Bulk::gather), but returns an error if an index is out of bounds, instead of panicking when it is reached.
+    /// The indices are checked by iterating a clone of them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let table: &[_] = &['a', 'b', 'c', 'd'];
+    ///
+    /// let picked: [_; _] = table.bulk()
+    ///     .try_gather([3, 0])
+    ///     .unwrap()
+    ///     .copied()
+    ///     .collect();
+    ///
+    /// assert_eq!(picked, ['d', 'a']);
+    /// assert!(matches!(
+    ///     table.bulk()
+    ///         .try_gather([3, 4, 0]),
+    ///     Err(OutOfRange { i: 4, len: 4 })
+    /// ));
+    /// ```
+    pub const fn try_gather<I>(self, indices: I) -> Result<slice::Gather<'a, T, I::IntoBulk>, OutOfRange>
+    where
+        I: ~const IntoBulk<Item = usize, IntoBulk: ~const Clone + ~const Destruct>
+    {
+        let Self { slice } = self;
+        let indices = indices.into_bulk();
+        match indices.clone().try_for_each(CheckIndex { len: slice.len() })
+        {
+            Ok(()) => Ok(slice::Gather {
+                slice,
+                indices
+            }),
+            Err(err) => Err(err)
+        }
+    }
+}
+impl<'a, T> slice::BulkMut<'a, T>
+{
+    /// Returns a bulk of `N` elements of the slice at a time, as mutable references to arrays.
+    ///
+    /// Like [`Bulk::array_chunks`], but the chunks point straight into the slice instead of being copied into arrays.
+    /// If `N` does not divide the length of the slice, then the last up to `N-1` elements will be omitted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let data: &mut [_] = &mut [1, 2, 3, 4];
+    ///
+    /// data.bulk_mut()
+    ///     .array_chunks()
+    ///     .for_each(|[a, b]: &mut [_; 2]| core::mem::swap(a, b));
+    ///
+    /// assert_eq!(data, [2, 1, 4, 3]);
+    /// ```
+    #[track_caller]
+    pub const fn array_chunks<const N: usize>(self) -> slice::ArrayChunksMut<'a, T, N>
+    {
+        let Self { slice } = self;
+        slice::ArrayChunksMut::new(slice)
+    }
+
+    /// Rearranges the slice in-place, such that the element at position `i` is moved from position `perm[i]`.
+    ///
+    /// The permutation is verified before anything is moved. It's invalid if an index is out of bounds,
+    /// or if an index occurs more than once, in which case an error is returned and the slice is left untouched.
+    ///
+    /// Since nothing is allocated, both verifying the permutation and finding its cycles take *O*(*n*<sup>2</sup>) time
+    /// in the worst case, where *n* is the length of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the length of the permutation differs from the length of the slice.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let data: &mut [_] = &mut ['a', 'b', 'c', 'd'];
+    ///
+    /// std::assert_matches!(
+    ///     data.bulk_mut()
+    ///         .permute(&[2, 0, 3, 1]),
+    ///     Ok(())
+    /// );
+    /// std::assert_matches!(
+    ///     data.bulk_mut()
+    ///         .permute(&[0, 1, 1, 2]),
+    ///     Err(IndexError::DuplicateIndex(DuplicateIndex { i: 1 }))
+    /// );
+    ///
+    /// assert_eq!(data, ['c', 'a', 'd', 'b']);
+    /// ```
+    #[track_caller]
+    pub const fn permute(self, perm: &[usize]) -> Result<(), IndexError>
+    {
+        let Self { slice } = self;
+        let len = slice.len();
+        if perm.len() != len
+        {
+            LengthMismatch { lhs: len, rhs: perm.len() }.halt()
+        }
+
+        if let Err(err) = check_disjoint(perm, len)
+        {
+            return Err(err)
+        }
+
+        // Every cycle of the permutation is rotated once, starting from its smallest position.
+        let mut i = 0;
+        while i < len
+        {
+            let mut j = perm[i];
+            while j > i
+            {
+                j = perm[j];
+            }
+            if j == i
+            {
+                let mut k = i;
+                loop
+                {
+                    let next = perm[k];
+                    if next == i
+                    {
+                        break
+                    }
+                    slice.swap(k, next);
+                    k = next;
+                }
+            }
+            i += 1;
+        }
+        Ok(())
+    }
+
+    /// Returns mutable references to the elements at several positions of the slice at once.
+    ///
+    /// Returns an error if an index is out of bounds, or if an index occurs more than once, since
+    /// that would hand out two mutable references to the same element.
+    ///
+    /// Similar to [`<[T]>::get_disjoint_mut`](slice::get_disjoint_mut).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use bulks::*;
+    ///
+    /// let data: &mut [_] = &mut [1, 2, 3, 4];
+    ///
+    /// if let Ok([a, b]) = data.bulk_mut().get_many_mut([0, 3])
+    /// {
+    ///     core::mem::swap(a, b);
+    /// }
+    /// assert_eq!(data, [4, 2, 3, 1]);
+    ///
+    /// std::assert_matches!(
+    ///     data.bulk_mut().get_many_mut([1, 1]),
+    ///     Err(IndexError::DuplicateIndex(DuplicateIndex { i: 1 }))
+    /// );
+    /// ```
+    pub const fn get_many_mut<const K: usize>(self, indices: [usize; K]) -> Result<[&'a mut T; K], IndexError>
+    {
+        if let Err(err) = check_disjoint(&indices, self.slice.len())
+        {
+            return Err(err)
+        }
+        Ok(unsafe {
+            self.get_many_unchecked_mut(indices)
+        })
+    }
+
+    /// Returns mutable references to the elements at several positions of the slice at once, without checking the indices.
+    ///
+    /// For a safe alternative, see [`get_many_mut`](slice::BulkMut::get_many_mut).
+    ///
+    /// # Safety
+    ///
+    /// Every index must be in bounds, and no index may occur more than once.
+    pub const unsafe fn get_many_unchecked_mut<const K: usize>(self, indices: [usize; K]) -> [&'a mut T; K]
+    {
+        let Self { slice } = self;
+        let ptr = slice.as_mut_ptr();
+        let mut refs = [const { MaybeUninit::uninit() }; K];
+        let mut k = 0;
+        while k < K
+        {
+            refs[k].write(unsafe {
+                &mut *ptr.add(indices[k])
+            });
+            k += 1;
+        }
+        unsafe {
+            refs.transpose().assume_init()
+        }
+    }
+}
+
+/// Checks that every index is less than `len`, and that no index occurs more than once.
+///
+/// Every index is compared with all the ones before it, so this takes quadratic time.
+pub(crate) const fn check_disjoint(indices: &[usize], len: usize) -> Result<(), IndexError>
+{
+    let mut k = 0;
+    while k < indices.len()
+    {
+        let i = indices[k];
+        if i >= len
+        {
+            return Err(IndexError::OutOfRange(OutOfRange { i, len }))
+        }
+        let mut l = 0;
+        while l < k
+        {
+            if indices[l] == i
+            {
+                return Err(IndexError::DuplicateIndex(DuplicateIndex { i }))
+            }
+            l += 1;
+        }
+        k += 1;
+    }
+    Ok(())
+}
+
+impl<'a, T, const N: usize, L> slice::ArrayChunks<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    #[track_caller]
+    pub(crate) const fn new(slice: &'a [T]) -> Self
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        let (chunks, _) = slice.as_chunks();
+        Self {
+            chunks,
+            length: PhantomData
+        }
+    }
+}
+impl<'a, T, const N: usize, L> slice::ArrayChunksMut<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    #[track_caller]
+    pub(crate) const fn new(slice: &'a mut [T]) -> Self
+    {
+        assert!(N != 0, "chunk size must be non-zero");
+        let (chunks, _) = slice.as_chunks_mut();
+        Self {
+            chunks,
+            length: PhantomData
+        }
+    }
+}
+impl<'a, T, const N: usize, L> slice::ArrayWindows<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    #[track_caller]
+    pub(crate) const fn new(slice: &'a [T]) -> Self
+    {
+        assert!(N != 0, "window size must be non-zero");
+        Self {
+            slice,
+            length: PhantomData
+        }
+    }
+
+    const fn window(&self, n: usize) -> &'a [T; N]
+    {
+        let Self { slice, length: _ } = self;
+        assert!(n + N <= slice.len());
+        unsafe {
+            &*slice.as_ptr().add(n).cast::<[T; N]>()
+        }
+    }
+}
+
+impl<'a, T, const N: usize, L> IntoIterator for slice::ArrayChunks<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    type Item = &'a [T; N];
+    type IntoIter = core::slice::Iter<'a, [T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { chunks, length: _ } = self;
+        chunks.iter()
+    }
+}
+impl<'a, T, const N: usize, L> IntoIterator for slice::ArrayChunksMut<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    type Item = &'a mut [T; N];
+    type IntoIter = core::slice::IterMut<'a, [T; N]>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { chunks, length: _ } = self;
+        chunks.iter_mut()
+    }
+}
+impl<'a, T, const N: usize, L> IntoIterator for slice::ArrayWindows<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    type Item = &'a [T; N];
+    type IntoIter = core::slice::ArrayWindows<'a, T, N>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { slice, length: _ } = self;
+        slice.array_windows()
+    }
+}
+
+const impl<'a, T, const N: usize, L> Bulk for slice::ArrayChunks<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    type MinLength = length::Div<L, [(); N]>;
+    type MaxLength = length::Div<L, [(); N]>;
+
+    fn len(&self) -> usize
+    {
+        self.chunks.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        self.chunks.is_empty()
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { chunks, length: _ } = self;
+        let len = chunks.len();
+        let mut n = 0;
+        while n < len
+        {
+            f(&chunks[n]);
+            n += 1;
+        }
+    }
+    fn try_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { chunks, length: _ } = self;
+        let len = chunks.len();
+        let mut n = 0;
+        while n < len
+        {
+            f(&chunks[n])?;
+            n += 1;
+        }
+        R::from_output(())
+    }
+}
+const impl<'a, T, const N: usize, L> DoubleEndedBulk for slice::ArrayChunks<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    fn rev_for_each<F>(self, mut f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { chunks, length: _ } = self;
+        let mut n = chunks.len();
+        while n > 0
+        {
+            n -= 1;
+            f(&chunks[n]);
+        }
+    }
+    fn try_rev_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { chunks, length: _ } = self;
+        let mut n = chunks.len();
+        while n > 0
+        {
+            n -= 1;
+            f(&chunks[n])?;
+        }
+        R::from_output(())
+    }
+}
+
+const impl<'a, T, const N: usize, L> Bulk for slice::ArrayChunksMut<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    type MinLength = length::Div<L, [(); N]>;
+    type MaxLength = length::Div<L, [(); N]>;
+
+    fn len(&self) -> usize
+    {
+        self.chunks.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        self.chunks.is_empty()
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { chunks, length: _ } = self;
+        let len = chunks.len();
+        let mut n = 0;
+        while n < len
+        {
+            f(unsafe {&mut *(&mut chunks[n] as *mut _)});
+            n += 1;
+        }
+    }
+    fn try_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { chunks, length: _ } = self;
+        let len = chunks.len();
+        let mut n = 0;
+        while n < len
+        {
+            f(unsafe {&mut *(&mut chunks[n] as *mut _)})?;
+            n += 1;
+        }
+        R::from_output(())
+    }
+}
+const impl<'a, T, const N: usize, L> DoubleEndedBulk for slice::ArrayChunksMut<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    fn rev_for_each<F>(self, mut f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { chunks, length: _ } = self;
+        let mut n = chunks.len();
+        while n > 0
+        {
+            n -= 1;
+            f(unsafe {&mut *(&mut chunks[n] as *mut _)});
+        }
+    }
+    fn try_rev_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { chunks, length: _ } = self;
+        let mut n = chunks.len();
+        while n > 0
+        {
+            n -= 1;
+            f(unsafe {&mut *(&mut chunks[n] as *mut _)})?;
+        }
+        R::from_output(())
+    }
+}
+
+const impl<'a, T, const N: usize, L> Bulk for slice::ArrayWindows<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    type MinLength = length::Windowed<L, [(); N]>;
+    type MaxLength = length::Windowed<L, [(); N]>;
+
+    fn len(&self) -> usize
+    {
+        self.slice.len().saturating_sub(N - 1)
+    }
+    fn is_empty(&self) -> bool
+    {
+        self.slice.len() < N
+    }
+
+    fn for_each<F>(self, mut f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let len = self.len();
+        let mut n = 0;
+        while n < len
+        {
+            f(self.window(n));
+            n += 1;
+        }
+    }
+    fn try_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let len = self.len();
+        let mut n = 0;
+        while n < len
+        {
+            f(self.window(n))?;
+            n += 1;
+        }
+        R::from_output(())
+    }
+}
+const impl<'a, T, const N: usize, L> DoubleEndedBulk for slice::ArrayWindows<'a, T, N, L>
+where
+    L: Length<Elem = ()> + ?Sized
+{
+    fn rev_for_each<F>(self, mut f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let mut n = self.len();
+        while n > 0
+        {
+            n -= 1;
+            f(self.window(n));
+        }
+    }
+    fn try_rev_for_each<F, R>(self, mut f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let mut n = self.len();
+        while n > 0
+        {
+            n -= 1;
+            f(self.window(n))?;
+        }
+        R::from_output(())
+    }
+}
+
+const impl<'a, T> IntoCursor for slice::Bulk<'a, T>
+where
+    T: 'a
+{
+    type Cursor = private::Cursor<'a, T>;
+
+    fn into_cursor(self) -> Self::Cursor
+    {
+        private::Cursor {
+            slice: self.slice
+        }
+    }
+}
+const impl<'a, T> IntoCursor for slice::BulkMut<'a, T>
+where
+    T: 'a
+{
+    type Cursor = private::CursorMut<'a, T>;
+
+    fn into_cursor(self) -> Self::Cursor
+    {
+        private::CursorMut {
+            slice: self.slice
+        }
+    }
+}
+
+mod private
+{
+    pub struct Cursor<'a, T>
+    {
+        pub slice: &'a [T]
+    }
+
+    const impl<'a, T> Iterator for Cursor<'a, T>
+    {
+        type Item = &'a T;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let (first, rest) = self.slice.split_first()?;
+            self.slice = rest;
+            Some(first)
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let len = self.slice.len();
+            (len, Some(len))
+        }
+    }
+
+    pub struct CursorMut<'a, T>
+    {
+        pub slice: &'a mut [T]
+    }
+
+    const impl<'a, T> Iterator for CursorMut<'a, T>
+    {
+        type Item = &'a mut T;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let (first, rest) = core::mem::take(&mut self.slice).split_first_mut()?;
+            self.slice = rest;
+            Some(first)
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let len = self.slice.len();
+            (len, Some(len))
+        }
+    }
+
+    pub struct Gather<'a, T, I>
+    where
+        I: Iterator<Item = usize>
+    {
+        pub slice: &'a [T],
+        pub indices: I
+    }
+
+    impl<'a, T, I> Iterator for Gather<'a, T, I>
+    where
+        I: Iterator<Item = usize>
+    {
+        type Item = &'a T;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            let Self { slice, indices } = self;
+            Some(super::gather(slice, indices.next()?))
+        }
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            self.indices.size_hint()
+        }
+    }
+    impl<'a, T, I> ExactSizeIterator for Gather<'a, T, I>
+    where
+        I: ExactSizeIterator<Item = usize>
+    {
+        fn len(&self) -> usize
+        {
+            self.indices.len()
+        }
+    }
+    impl<'a, T, I> DoubleEndedIterator for Gather<'a, T, I>
+    where
+        I: DoubleEndedIterator<Item = usize>
+    {
+        fn next_back(&mut self) -> Option<Self::Item>
+        {
+            let Self { slice, indices } = self;
+            Some(super::gather(slice, indices.next_back()?))
+        }
+    }
+}
+
+#[track_caller]
+const fn gather<T>(slice: &[T], i: usize) -> &T
+{
+    let len = slice.len();
+    if i >= len
+    {
+        OutOfRange { i, len }.halt()
+    }
+    &slice[i]
+}
+
+struct CheckIndex
+{
+    len: usize
+}
+const impl FnOnce<(usize,)> for CheckIndex
+{
+    type Output = Result<(), OutOfRange>;
+
+    extern "rust-call" fn call_once(mut self, args: (usize,)) -> Self::Output
+    {
+        self.call_mut(args)
+    }
+}
+const impl FnMut<(usize,)> for CheckIndex
+{
+    extern "rust-call" fn call_mut(&mut self, (i,): (usize,)) -> Self::Output
+    {
+        let Self { len } = *self;
+        if i >= len
+        {
+            return Err(OutOfRange { i, len })
+        }
+        Ok(())
+    }
+}
+
+struct Gather<'a, T, F>
+{
+    slice: &'a [T],
+    f: F
+}
+const impl<'a, T, F, R> FnOnce<(usize,)> for Gather<'a, T, F>
+where
+    F: ~const FnOnce(&'a T) -> R
+{
+    type Output = R;
+
+    extern "rust-call" fn call_once(self, (i,): (usize,)) -> Self::Output
+    {
+        let Self { slice, f } = self;
+        f(gather(slice, i))
+    }
+}
+const impl<'a, T, F, R> FnMut<(usize,)> for Gather<'a, T, F>
+where
+    F: ~const FnMut(&'a T) -> R
+{
+    extern "rust-call" fn call_mut(&mut self, (i,): (usize,)) -> Self::Output
+    {
+        let Self { slice, f } = self;
+        f(gather(slice, i))
+    }
+}
+
+impl<'a, T, I> IntoIterator for slice::Gather<'a, T, I>
+where
+    I: Bulk<Item = usize>
+{
+    type Item = &'a T;
+    type IntoIter = private::Gather<'a, T, I::IntoIter>;
+
+    fn into_iter(self) -> Self::IntoIter
+    {
+        let Self { slice, indices } = self;
+        private::Gather {
+            slice,
+            indices: indices.into_iter()
+        }
+    }
+}
+const impl<'a, T, I> Bulk for slice::Gather<'a, T, I>
+where
+    I: ~const Bulk<Item = usize>
+{
+    type MinLength = I::MinLength;
+    type MaxLength = I::MaxLength;
+
+    fn len(&self) -> usize
+    {
+        self.indices.len()
+    }
+    fn is_empty(&self) -> bool
+    {
+        self.indices.is_empty()
+    }
+
+    fn for_each<F>(self, f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { slice, indices } = self;
+        indices.for_each(Gather { slice, f })
+    }
+    fn try_for_each<F, R>(self, f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { slice, indices } = self;
+        indices.try_for_each(Gather { slice, f })
+    }
+}
+const impl<'a, T, I> DoubleEndedBulk for slice::Gather<'a, T, I>
+where
+    I: ~const DoubleEndedBulk<Item = usize> + ~const Bulk
+{
+    fn rev_for_each<F>(self, f: F)
+    where
+        F: ~const FnMut(Self::Item) + ~const Destruct
+    {
+        let Self { slice, indices } = self;
+        indices.rev_for_each(Gather { slice, f })
+    }
+    fn try_rev_for_each<F, R>(self, f: F) -> R
+    where
+        Self::Item: ~const Destruct,
+        F: ~const FnMut(Self::Item) -> R + ~const Destruct,
+        R: ~const Try<Output = (), Residual: ~const Destruct>
+    {
+        let Self { slice, indices } = self;
+        indices.try_rev_for_each(Gather { slice, f })
+    }
+}
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn views()
+    {
+        let mut a = [1, 2, 3, 4, 5, 6, 7];
+
+        let chunks: [&[_; 3]; 2] = a.bulk()
+            .array_chunks()
+            .collect();
+        assert_eq!(chunks, [&[1, 2, 3], &[4, 5, 6]]);
+        assert!(core::ptr::eq(chunks[0], a[..3].as_array().unwrap()));
+
+        let windows: Vec<_> = a.as_slice()
+            .bulk()
+            .array_windows::<6>()
+            .rev()
+            .collect();
+        assert_eq!(windows, [&[2, 3, 4, 5, 6, 7], &[1, 2, 3, 4, 5, 6]]);
+
+        a.bulk_mut()
+            .array_chunks::<2>()
+            .for_each(|c| c.reverse());
+        assert_eq!(a, [2, 1, 4, 3, 6, 5, 7]);
+    }
+
+    #[test]
+    fn permutation()
+    {
+        let mut a = [10, 11, 12, 13, 14];
+        let perm = [4, 2, 3, 0, 1];
+
+        let gathered: [_; 5] = a.bulk()
+            .gather(perm)
+            .copied()
+            .collect();
+        assert_eq!(gathered, [14, 12, 13, 10, 11]);
+        assert!(matches!(
+            a.bulk().try_gather([0, 5]),
+            Err(OutOfRange { i: 5, len: 5 })
+        ));
+
+        let picked: [_; 2] = a.bulk()
+            .try_gather([4, 0])
+            .unwrap()
+            .copied()
+            .collect();
+        assert_eq!(picked, [14, 10]);
+
+        assert!(a.as_mut_slice().bulk_mut().permute(&perm).is_ok());
+        assert_eq!(a, gathered);
+
+        let mut b = [0; 5];
+        assert!(gathered.into_bulk().scatter_into(&mut b, perm).is_ok());
+        assert_eq!(b, [10, 11, 12, 13, 14]);
+
+        const SCATTERED: [i32; 5] = {
+            let mut b = [0; 5];
+            assert!([14, 12, 13, 10, 11].into_bulk().scatter_into(&mut b, [4, 2, 3, 0, 1]).is_ok());
+            b
+        };
+        assert_eq!(SCATTERED, [10, 11, 12, 13, 14]);
+
+        assert!(matches!(
+            a.as_mut_slice().bulk_mut().permute(&[0, 1, 2, 3, 5]),
+            Err(IndexError::OutOfRange(OutOfRange { i: 5, len: 5 }))
+        ));
+        assert_eq!(a, gathered);
+    }
+
+    #[test]
+    fn scatter_bounded()
+    {
+        let indices: &[_] = &[2, 0];
+
+        let mut a = [0; 3];
+        assert!([1, 2, 3, 4].into_bulk()
+            .filter(|x| x % 2 == 0)
+            .scatter_into(&mut a, indices.bulk().copied())
+            .is_ok());
+        assert_eq!(a, [4, 0, 2]);
+
+        let mut b = [0; 3];
+        assert!(indices.bulk()
+            .copied()
+            .scatter_into(&mut b, [0, 5, 1, 7].into_bulk().filter(|&i| i < 3))
+            .is_ok());
+        assert_eq!(b, [2, 0, 0]);
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn get_many_mut()
+    {
+        let mut a = vec![1, 2, 3, 4];
+
+        let [x, y, z] = a.bulk_mut().get_many_mut([3, 0, 1]).unwrap();
+        *x += 10;
+        *y += 20;
+        *z += 30;
+        assert_eq!(a, [21, 32, 3, 14]);
+
+        assert!(matches!(
+            a.bulk_mut().get_many_mut([0, 4]),
+            Err(IndexError::OutOfRange(OutOfRange { i: 4, len: 4 }))
+        ));
+        assert!(matches!(
+            a.bulk_mut().get_many_mut([2, 0, 2]),
+            Err(IndexError::DuplicateIndex(DuplicateIndex { i: 2 }))
+        ));
+
+        let mut b = [1, 2, 3];
+
+        let [x, y] = b.bulk_mut().get_disjoint_mut(const { array::DisjointIndices::new([2, 1]) });
+        core::mem::swap(x, y);
+        assert_eq!(b, [1, 3, 2]);
+
+        assert!(array::DisjointIndices::<2, 3>::try_new([0, 3]).is_err());
+    }
+}
@@ -0,0 +1,139 @@
+use core::marker::Destruct;
+
+use array_trait::same::Same;
+
+use crate::{Bulk, array};
+
+/// Pulls the elements of a bulk one at a time.
+///
+/// Unlike [`IntoIterator`], this can be done in `const` for the bulks that support it. Bulks are pulled like this
+/// when they are consumed alongside another bulk, as in [`Bulk::eq`] and [`Bulk::cmp`].
+///
+/// This trait is sealed, and implemented for every bulk. Array and slice bulks can be pulled in `const`.
+pub const trait IntoCursor: Bulk + private::Sealed
+{
+    /// The iterator the elements are pulled from.
+    type Cursor: ~const Iterator<Item = Self::Item> + ~const Destruct;
+
+    /// Turns the bulk into an iterator that can be advanced in `const` if the bulk supports it.
+    fn into_cursor(self) -> Self::Cursor;
+}
+
+impl<T> IntoCursor for T
+where
+    T: Bulk
+{
+    default type Cursor = T::IntoIter;
+
+    default fn into_cursor(self) -> Self::Cursor
+    {
+        self.into_iter().same().ok().unwrap()
+    }
+}
+
+const impl<T, const N: usize> IntoCursor for array::IntoBulk<T, N>
+where
+    T: ~const Destruct
+{
+    type Cursor = private::ArrayCursor<T, N>;
+
+    fn into_cursor(self) -> Self::Cursor
+    {
+        private::ArrayCursor::new(self.into_inner())
+    }
+}
+const impl<'a, T, const N: usize> IntoCursor for array::Bulk<'a, T, N>
+{
+    type Cursor = private::ArrayCursor<&'a T, N>;
+
+    fn into_cursor(self) -> Self::Cursor
+    {
+        private::ArrayCursor::new(self.into_inner().each_ref())
+    }
+}
+const impl<'a, T, const N: usize> IntoCursor for array::BulkMut<'a, T, N>
+{
+    type Cursor = private::ArrayCursor<&'a mut T, N>;
+
+    fn into_cursor(self) -> Self::Cursor
+    {
+        private::ArrayCursor::new(self.into_inner().each_mut())
+    }
+}
+
+mod private
+{
+    use core::{marker::Destruct, mem::MaybeUninit, ops::Range};
+
+    use crate::Bulk;
+
+    pub trait Sealed
+    {
+
+    }
+    impl<T> Sealed for T
+    where
+        T: Bulk
+    {
+
+    }
+
+    pub struct ArrayCursor<T, const N: usize>
+    {
+        array: [MaybeUninit<T>; N],
+        initialized: Range<usize>
+    }
+
+    impl<T, const N: usize> ArrayCursor<T, N>
+    {
+        pub const fn new(array: [T; N]) -> Self
+        {
+            Self {
+                array: MaybeUninit::new(array).transpose(),
+                initialized: 0..N
+            }
+        }
+    }
+
+    const impl<T, const N: usize> Iterator for ArrayCursor<T, N>
+    {
+        type Item = T;
+
+        fn next(&mut self) -> Option<Self::Item>
+        {
+            if self.initialized.start >= self.initialized.end
+            {
+                return None
+            }
+            let i = self.initialized.start;
+            self.initialized.start += 1;
+            // SAFETY: Every element in `initialized` has been written, and is read at most once.
+            Some(unsafe {
+                self.array[i].assume_init_read()
+            })
+        }
+
+        fn size_hint(&self) -> (usize, Option<usize>)
+        {
+            let len = self.initialized.end - self.initialized.start;
+            (len, Some(len))
+        }
+    }
+
+    const impl<T, const N: usize> Drop for ArrayCursor<T, N>
+    where
+        T: ~const Destruct
+    {
+        fn drop(&mut self)
+        {
+            while self.initialized.start < self.initialized.end
+            {
+                // SAFETY: Every element in `initialized` has been written, and not yet been read.
+                unsafe {
+                    self.array[self.initialized.start].assume_init_drop();
+                }
+                self.initialized.start += 1;
+            }
+        }
+    }
+}
@@ -348,18 +348,23 @@ moddef::moddef!(
     flat(pub) mod {
         adapters,
         impl_array,
+        impl_bounded_array,
         impl_iter,
         impl_ndarray for cfg(feature = "ndarray"),
         impl_slice,
         impl_vec for cfg(feature = "alloc"),
         impl_option,
+        bounded,
         bulk,
         collect_nearest,
         double_ended_bulk,
         from_bulk,
         into_bulk,
+        into_cursor,
         split_bulk,
-        static_bulk
+        split_into_bulk,
+        static_bulk,
+        unzip_bulk
     },
     pub mod {
         range
@@ -425,7 +430,7 @@ mod tests
 
         let b = a.bulk().copied().map(f).enumerate().inspect(|(i, x)| assert_eq!(i, x));
 
-        fn nearest<T>(bulk: T) -> <BulkLength<T> as Nearest>::NearestFrom<T>
+        fn nearest<T>(bulk: T) -> <NearestLength<T> as Nearest<T::MaxLength, T::MinLength>>::NearestFrom<T>
         where
             T: Bulk + CollectNearest
         {
@@ -437,6 +442,24 @@ mod tests
         assert_eq!(b, [(0, 0), (1, 1), (2, 2)] as [(usize, usize); _]);
     }
 
+    #[test]
+    fn nearest_bounded()
+    {
+        let a: &[i32] = &[1, 2, 3, 4, 5];
+
+        let b: BoundedArray<_, 3> = a.bulk()
+            .copied()
+            .take([(); 3])
+            .collect_nearest();
+        assert_eq!(b.as_slice(), [1, 2, 3]);
+
+        let n = 2;
+        let c: BoundedArray<_, 3> = range(0, n)
+            .take([(); 3])
+            .collect_nearest();
+        assert_eq!(c.as_slice(), [0, 1]);
+    }
+
     #[test]
     fn test_option()
     {
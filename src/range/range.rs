@@ -94,8 +94,8 @@ where
     S: Length<Elem = ()> + ?Sized,
     E: Length<Elem = ()> + ?Sized
 {
-    type MinLength = length::SaturatingSub<E, S>;
-    type MaxLength = length::SaturatingSub<E, S>;
+    type MinLength = <E as private::LengthSpec<S>>::MinLength;
+    type MaxLength = <E as private::LengthSpec<S>>::MaxLength;
     
     fn len(&self) -> usize
     {
@@ -215,6 +215,47 @@ where
     }
 }
 
+mod private
+{
+    use array_trait::length::{self, Length};
+
+    /// The bounds of the length of a range from `S` to `Self`.
+    ///
+    /// A range that ends at a length only known at runtime is unbounded, like a slice.
+    pub trait LengthSpec<S>: Length<Elem = ()>
+    where
+        S: Length<Elem = ()> + ?Sized
+    {
+        type MinLength: Length<Elem = ()> + ?Sized;
+        type MaxLength: Length<Elem = ()> + ?Sized;
+    }
+    impl<E, S> LengthSpec<S> for E
+    where
+        E: Length<Elem = ()> + ?Sized,
+        S: Length<Elem = ()> + ?Sized
+    {
+        default type MinLength = length::SaturatingSub<E, S>;
+        default type MaxLength = length::SaturatingSub<E, S>;
+    }
+    impl<S> LengthSpec<S> for [()]
+    where
+        S: Length<Elem = ()> + ?Sized
+    {
+        type MinLength = [(); 0];
+        type MaxLength = [()];
+    }
+    impl<const E: usize> LengthSpec<[()]> for [(); E]
+    {
+        type MinLength = [(); 0];
+        type MaxLength = [(); E];
+    }
+    impl<const E: usize, const S: usize> LengthSpec<[(); S]> for [(); E]
+    {
+        type MinLength = length::SaturatingSub<[(); E], [(); S]>;
+        type MaxLength = length::SaturatingSub<[(); E], [(); S]>;
+    }
+}
+
 #[cfg(test)]
 mod test
 {
@@ -0,0 +1,87 @@
+use array_trait::length::{self, LengthValue, Value};
+
+use crate::{Bulk, BulkLength, SplitBulk};
+
+/// The length of each part when [`Bulk::split_into`] splits a bulk into `K` parts.
+pub type SplitIntoLength<B, const K: usize> = length::value::Div<Value<BulkLength<B>>, [(); K]>;
+
+pub const trait SplitIntoBulk<L, const K: usize>: ~const Bulk
+where
+    L: LengthValue
+{
+    type Part: Bulk<Item = Self::Item>;
+
+    /// Splits a bulk into `K` consecutive parts of length `n`.
+    ///
+    /// Every split but the last must leave a left half of the same type, so if the parts
+    /// of a bulk with a length known at compile-time can't all be of equal length, this trait is not implemented.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # #![feature(generic_const_exprs)]
+    /// use bulks::*;
+    ///
+    /// let a = [1, 2, 3, 4, 5, 6];
+    ///
+    /// let [a1, a2, a3] = SplitIntoBulk::split_into(a.into_bulk(), [(); 2]);
+    ///
+    /// let a1: [_; _] = a1.collect();
+    /// let a2: [_; _] = a2.collect();
+    /// let a3: [_; _] = a3.collect();
+    ///
+    /// assert_eq!(a1, [1, 2]);
+    /// assert_eq!(a2, [3, 4]);
+    /// assert_eq!(a3, [5, 6]);
+    /// ```
+    #[track_caller]
+    fn split_into(bulk: Self, n: L) -> [Self::Part; K]
+    where
+        Self: Sized;
+}
+
+const impl<B, L> SplitIntoBulk<L, 1> for B
+where
+    B: ~const Bulk,
+    L: LengthValue
+{
+    type Part = B;
+
+    fn split_into(bulk: Self, _n: L) -> [Self::Part; 1]
+    where
+        Self: Sized
+    {
+        [bulk]
+    }
+}
+
+macro_rules! impl_split_into {
+    ($k:literal; $($b:ident => $r:ident $x:ident),+) => {
+        const impl<$($b,)+ L, P> SplitIntoBulk<L, $k> for B
+        where
+            $($b: ~const SplitBulk<L, Left = P, Right = $r>,)+
+            L: LengthValue,
+            P: Bulk<Item = <B as IntoIterator>::Item>
+        {
+            type Part = P;
+
+            fn split_into(bulk: Self, n: L) -> [Self::Part; $k]
+            where
+                Self: Sized
+            {
+                $(
+                    let ($x, bulk) = SplitBulk::<L>::split_at(bulk, n);
+                )+
+                [$($x,)+ bulk]
+            }
+        }
+    };
+}
+
+impl_split_into!(2; B => P x0);
+impl_split_into!(3; B => R1 x0, R1 => P x1);
+impl_split_into!(4; B => R1 x0, R1 => R2 x1, R2 => P x2);
+impl_split_into!(5; B => R1 x0, R1 => R2 x1, R2 => R3 x2, R3 => P x3);
+impl_split_into!(6; B => R1 x0, R1 => R2 x1, R2 => R3 x2, R3 => R4 x3, R4 => P x4);
+impl_split_into!(7; B => R1 x0, R1 => R2 x1, R2 => R3 x2, R3 => R4 x3, R4 => R5 x4, R5 => P x5);
+impl_split_into!(8; B => R1 x0, R1 => R2 x1, R2 => R3 x2, R3 => R4 x3, R4 => R5 x4, R5 => R6 x5, R6 => P x6);
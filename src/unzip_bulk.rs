@@ -0,0 +1,193 @@
+use core::marker::{Destruct, PhantomData};
+
+use crate::{Bulk, BulkLength, CollectionAdapter, CollectionStrategy, FromBulk};
+
+pub(crate) use private::UnzipBuffer as UnzipBuffer;
+
+/// Conversion of a [`Bulk`] of tuples into a tuple of collections.
+///
+/// This is what [`Bulk::unzip`] and [`Bulk::multiunzip`] use under the hood. It is implemented for all bulks
+/// yielding tuples with two to eight elements.
+///
+/// The first element of each tuple is collected directly from the bulk, while the other elements are buffered
+/// in a collection of the same length as the bulk, so the bulk is only consumed once. If the bulk has a length
+/// known at compile-time, the buffers are arrays, otherwise they are `Vec`s.
+///
+/// # Examples
+///
+/// ```
+/// use bulks::*;
+///
+/// let a = [(1, 'a', "one"), (2, 'b', "two"), (3, 'c', "three")];
+///
+/// let (numbers, letters, words): ([_; _], [_; _], [_; _]) = UnzipBulk::unzip(a.into_bulk());
+///
+/// assert_eq!(numbers, [1, 2, 3]);
+/// assert_eq!(letters, ['a', 'b', 'c']);
+/// assert_eq!(words, ["one", "two", "three"]);
+/// ```
+pub const trait UnzipBulk<C, A>: ~const Bulk
+{
+    /// Splits a bulk of tuples into a tuple of collections.
+    ///
+    /// See [`Bulk::multiunzip`] for more.
+    fn unzip(bulk: Self) -> C
+    where
+        Self: Sized;
+}
+
+mod private
+{
+    use core::marker::Destruct;
+
+    use array_trait::length::Length;
+
+    use crate::{Bulk, IntoBulk, array, util::ArrayBuffer};
+
+    pub const trait UnzipBuffer: Length<Elem = ()>
+    {
+        type Buffer<T>: ~const Destruct;
+        type Output<T>: ~const Bulk<Item = T>;
+
+        fn buffer<T>(len: usize) -> Self::Buffer<T>;
+        fn push<T>(buffer: &mut Self::Buffer<T>, value: T);
+        fn finish<T>(buffer: Self::Buffer<T>) -> Self::Output<T>;
+    }
+
+    const impl<const N: usize> UnzipBuffer for [(); N]
+    {
+        type Buffer<T> = ArrayBuffer<T, N, false>;
+        type Output<T> = array::IntoBulk<T, N>;
+
+        fn buffer<T>(len: usize) -> Self::Buffer<T>
+        {
+            assert!(len == N);
+            ArrayBuffer::new()
+        }
+        fn push<T>(buffer: &mut Self::Buffer<T>, value: T)
+        {
+            buffer.push(value)
+        }
+        fn finish<T>(mut buffer: Self::Buffer<T>) -> Self::Output<T>
+        {
+            buffer.take_array().unwrap().into_bulk()
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    impl UnzipBuffer for [()]
+    {
+        type Buffer<T> = alloc::vec::Vec<T>;
+        type Output<T> = crate::vec::IntoBulk<T>;
+
+        fn buffer<T>(len: usize) -> Self::Buffer<T>
+        {
+            alloc::vec::Vec::with_capacity(len)
+        }
+        fn push<T>(buffer: &mut Self::Buffer<T>, value: T)
+        {
+            buffer.push(value)
+        }
+        fn finish<T>(buffer: Self::Buffer<T>) -> Self::Output<T>
+        {
+            buffer.into_bulk()
+        }
+    }
+}
+
+type Unzipped<B, T> = <BulkLength<B> as UnzipBuffer>::Output<T>;
+
+macro_rules! impl_unzip {
+    ($t0:ident $c0:ident $a0:ident; $($t:ident $c:ident $a:ident $buffer:ident),+) => {
+        const impl<B, $t0, $($t,)+ $c0, $($c,)+ $a0, $($a,)+> UnzipBulk<($c0, $($c,)+), (PhantomData<$a0>, $(PhantomData<$a>,)+)> for B
+        where
+            B: ~const Bulk<Item = ($t0, $($t,)+)>,
+            BulkLength<B>: ~const UnzipBuffer,
+            $t0: ~const Destruct,
+            $($t: ~const Destruct,)+
+            $c0: ~const FromBulk<$a0>,
+            $($c: ~const FromBulk<$a>,)+
+            $a0: CollectionAdapter<Elem = $t0> + ~const CollectionStrategy<B::MinLength, B::MaxLength, $c0> + ?Sized,
+            $($a: CollectionAdapter<Elem = $t> + ~const CollectionStrategy<<Unzipped<B, $t> as Bulk>::MinLength, <Unzipped<B, $t> as Bulk>::MaxLength, $c> + ?Sized,)+
+        {
+            fn unzip(bulk: Self) -> ($c0, $($c,)+)
+            {
+                struct Splitter<'a, L, $($t,)+>
+                where
+                    L: UnzipBuffer + ?Sized
+                {
+                    $($buffer: &'a mut L::Buffer<$t>,)+
+                }
+                const impl<'a, L, $t0, $($t,)+> FnOnce<(($t0, $($t,)+),)> for Splitter<'a, L, $($t,)+>
+                where
+                    L: ~const UnzipBuffer + ?Sized
+                {
+                    type Output = $t0;
+
+                    extern "rust-call" fn call_once(mut self, args: (($t0, $($t,)+),)) -> Self::Output
+                    {
+                        self.call_mut(args)
+                    }
+                }
+                const impl<'a, L, $t0, $($t,)+> FnMut<(($t0, $($t,)+),)> for Splitter<'a, L, $($t,)+>
+                where
+                    L: ~const UnzipBuffer + ?Sized
+                {
+                    extern "rust-call" fn call_mut(&mut self, ((first, $($buffer,)+),): (($t0, $($t,)+),)) -> Self::Output
+                    {
+                        $(L::push(self.$buffer, $buffer);)+
+                        first
+                    }
+                }
+
+                let len = bulk.len();
+                $(let mut $buffer = <BulkLength<B> as UnzipBuffer>::buffer::<$t>(len);)+
+                let first = bulk.map(Splitter::<BulkLength<B>, $($t,)+> {
+                    $($buffer: &mut $buffer,)+
+                }).collect();
+                (
+                    first,
+                    $(<BulkLength<B> as UnzipBuffer>::finish($buffer).collect(),)+
+                )
+            }
+        }
+    };
+}
+
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1);
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1, T2 C2 A2 b2);
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1, T2 C2 A2 b2, T3 C3 A3 b3);
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1, T2 C2 A2 b2, T3 C3 A3 b3, T4 C4 A4 b4);
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1, T2 C2 A2 b2, T3 C3 A3 b3, T4 C4 A4 b4, T5 C5 A5 b5);
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1, T2 C2 A2 b2, T3 C3 A3 b3, T4 C4 A4 b4, T5 C5 A5 b5, T6 C6 A6 b6);
+impl_unzip!(T0 C0 A0; T1 C1 A1 b1, T2 C2 A2 b2, T3 C3 A3 b3, T4 C4 A4 b4, T5 C5 A5 b5, T6 C6 A6 b6, T7 C7 A7 b7);
+
+#[cfg(test)]
+mod test
+{
+    use crate::*;
+
+    #[test]
+    fn it_works()
+    {
+        let a = [(1, 'a'), (2, 'b'), (3, 'c')];
+
+        let (numbers, letters): ([_; _], Vec<_>) = a.into_bulk().unzip();
+
+        assert_eq!(numbers, [1, 2, 3]);
+        assert_eq!(letters, ['a', 'b', 'c']);
+    }
+
+    #[test]
+    fn in_const()
+    {
+        let (a, b, c) = const {
+            [(1u8, 2u16, 3u32), (4, 5, 6)].into_bulk()
+                .multiunzip::<([_; _], [_; _], [_; _]), _>()
+        };
+
+        assert_eq!(a, [1, 4]);
+        assert_eq!(b, [2, 5]);
+        assert_eq!(c, [3, 6]);
+    }
+}
@@ -0,0 +1,49 @@
+use core::marker::Destruct;
+
+pub struct Accumulator<T, F>
+{
+    pub(crate) acc: Option<T>,
+    pub(crate) f: F
+}
+
+const impl<T, F> Clone for Accumulator<T, F>
+where
+    T: ~const Clone + ~const Destruct,
+    F: ~const Clone
+{
+    fn clone(&self) -> Self
+    {
+        let Self { acc, f } = self;
+        Self {
+            acc: acc.clone(),
+            f: f.clone()
+        }
+    }
+}
+
+const impl<T, F, U> FnOnce<(U,)> for Accumulator<T, F>
+where
+    F: ~const FnOnce(T, U) -> T,
+    T: ~const Destruct
+{
+    type Output = T;
+
+    extern "rust-call" fn call_once(self, (x,): (U,)) -> Self::Output
+    {
+        let Self { acc, f } = self;
+        f(acc.unwrap(), x)
+    }
+}
+const impl<T, F, U> FnMut<(U,)> for Accumulator<T, F>
+where
+    F: ~const FnMut(T, U) -> T,
+    T: ~const Clone + ~const Destruct
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (U,)) -> Self::Output
+    {
+        let Self { acc, f } = self;
+        let y = f(acc.take().unwrap(), x);
+        *acc = Some(y.clone());
+        y
+    }
+}
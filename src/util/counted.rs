@@ -0,0 +1,88 @@
+use core::cell::Cell;
+
+/// An iterator whose length is only counted when it is asked for.
+///
+/// Used by bulks whose length can't be known without evaluating them, such as [`Filter`](crate::Filter).
+/// The length is found by counting a clone of the iterator the first time it is needed, and is then kept up to date.
+#[derive(Clone)]
+pub struct Counted<I>
+where
+    I: Iterator
+{
+    iter: I,
+    len: Cell<Option<usize>>
+}
+
+impl<I> Counted<I>
+where
+    I: Iterator
+{
+    pub const fn new(iter: I) -> Self
+    {
+        Self {
+            iter,
+            len: Cell::new(None)
+        }
+    }
+
+    fn advance(&self, next: &Option<I::Item>)
+    {
+        let Self { iter: _, len } = self;
+        if next.is_some() && let Some(n) = len.get()
+        {
+            len.set(Some(n - 1))
+        }
+    }
+}
+
+impl<I> Iterator for Counted<I>
+where
+    I: Iterator
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<Self::Item>
+    {
+        let next = self.iter.next();
+        self.advance(&next);
+        next
+    }
+    fn size_hint(&self) -> (usize, Option<usize>)
+    {
+        let Self { iter, len } = self;
+        match len.get()
+        {
+            Some(n) => (n, Some(n)),
+            None => iter.size_hint()
+        }
+    }
+}
+impl<I> ExactSizeIterator for Counted<I>
+where
+    I: Iterator + Clone
+{
+    fn len(&self) -> usize
+    {
+        let Self { iter, len } = self;
+        match len.get()
+        {
+            Some(n) => n,
+            None => {
+                let n = iter.clone().count();
+                len.set(Some(n));
+                n
+            }
+        }
+    }
+}
+impl<I> DoubleEndedIterator for Counted<I>
+where
+    I: DoubleEndedIterator
+{
+    fn next_back(&mut self) -> Option<Self::Item>
+    {
+        let next = self.iter.next_back();
+        self.advance(&next);
+        next
+    }
+}
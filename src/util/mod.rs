@@ -2,10 +2,13 @@ use core::{mem::{ManuallyDrop, MaybeUninit}};
 
 moddef::moddef!(
     flat(pub) mod {
+        accumulator,
+        counted,
         array_buffer,
         guard,
         infinite_iterator,
         mutator,
+        scanner,
         stepper,
         take_one,
         yield_once
@@ -0,0 +1,32 @@
+use core::marker::Destruct;
+
+pub struct Scanner<St, F>
+{
+    pub(crate) state: St,
+    pub(crate) f: F
+}
+
+const impl<St, F, T, U> FnOnce<(T,)> for Scanner<St, F>
+where
+    F: ~const FnOnce(&mut St, T) -> U,
+    St: ~const Destruct
+{
+    type Output = U;
+
+    extern "rust-call" fn call_once(self, (x,): (T,)) -> Self::Output
+    {
+        let Self { mut state, f } = self;
+        f(&mut state, x)
+    }
+}
+const impl<St, F, T, U> FnMut<(T,)> for Scanner<St, F>
+where
+    F: ~const FnMut(&mut St, T) -> U,
+    St: ~const Destruct
+{
+    extern "rust-call" fn call_mut(&mut self, (x,): (T,)) -> Self::Output
+    {
+        let Self { state, f } = self;
+        f(state, x)
+    }
+}